@@ -0,0 +1,63 @@
+//! Feeds arbitrary (value, from, to) triples through the generic converter
+//!
+//! Input layout: 8 bytes little-endian `f64` value, then one byte each
+//! selecting the source and target unit. Same-category conversions of a
+//! finite value must produce a finite result unless the value is so large
+//! that scaling by the unit factor overflows.
+
+#![no_main]
+
+use conversion_lib::{convert, Unit};
+use libfuzzer_sys::fuzz_target;
+
+/// Size of one `unit` in its base unit, ignoring any zero offset
+fn slope(unit: Unit) -> f64 {
+    (unit.to_base(1.0) - unit.to_base(0.0)).abs()
+}
+
+/// Largest magnitude that cannot overflow on the way through the base unit
+///
+/// The value is scaled by `from` into the base unit and then by `to` out of
+/// it, so both the intermediate and the final factor must fit. The Fahrenheit
+/// formulas multiply by 9 before dividing by 5, so leave a further factor of
+/// ten for that step and the temperature offsets.
+fn safe_magnitude(from: Unit, to: Unit) -> f64 {
+    let factor = slope(from).max(slope(from) / slope(to)).max(1.0);
+    f64::MAX / factor / 10.0
+}
+
+fuzz_target!(|data: &[u8]| {
+    let Some((value, codes)) = data.split_first_chunk::<8>() else {
+        return;
+    };
+    let &[from_code, to_code, ..] = codes else {
+        return;
+    };
+
+    let value = f64::from_le_bytes(*value);
+    let units = Unit::all();
+    let from = units[usize::from(from_code) % units.len()];
+    let to = units[usize::from(to_code) % units.len()];
+
+    match convert(value, from, to) {
+        Ok(result) => {
+            assert_eq!(from.category(), to.category());
+            if value.is_finite() && value.abs() < safe_magnitude(from, to) {
+                assert!(
+                    result.is_finite(),
+                    "{} {} -> {} gave {}",
+                    value,
+                    from,
+                    to,
+                    result
+                );
+            }
+        }
+        Err(_) => assert_ne!(from.category(), to.category()),
+    }
+
+    // Unit parsing must never panic on the same bytes
+    if let Ok(text) = std::str::from_utf8(codes) {
+        let _ = text.parse::<Unit>();
+    }
+});
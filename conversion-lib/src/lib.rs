@@ -19,9 +19,179 @@
 //! let fahrenheit = temperature::celsius_to_fahrenheit(25.0);
 //! assert_eq!(fahrenheit, 77.0);
 //! ```
+//!
+//! # Signed values and magnitudes
+//!
+//! Unit conversions are plain linear (or, for temperature, affine) functions and
+//! accept any sign: a negative length is a direction and converts to the
+//! same negative length in the target unit. Mass and pressure conversions also
+//! preserve sign, which makes them usable for differences such as a pressure drop.
+//!
+//! Engineering calculations that only make sense for magnitudes say so in their
+//! documentation and are kept apart from the plain conversions:
+//!
+//! * [`length::guess_unit`] looks at the absolute value only
+//! * [`pressure::hoop_stress`] rejects negative pressure, radius or thickness
+//! * [`flow::from_pressure_drop`] rejects a negative pressure drop, area or
+//!   discharge coefficient
+
+use std::collections::HashMap;
+use std::fmt;
+use std::str::FromStr;
+use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
+use std::sync::RwLock;
 
 /// Length conversion functions
+///
+/// All conversions in this module treat their input as a signed quantity.
 pub mod length {
+    use crate::{check_same_category, Category, Error, Lang, Quantity, Unit};
+    use std::collections::HashMap;
+    use std::str::FromStr;
+
+    /// Supported length units
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    pub enum LengthUnit {
+        Micrometers,
+        Millimeters,
+        Centimeters,
+        Meters,
+        Kilometers,
+        Inches,
+        Feet,
+        Yards,
+        Miles,
+        /// The US survey foot, withdrawn by NIST at the end of 2022
+        UsSurveyFeet,
+    }
+
+    impl LengthUnit {
+        /// Every supported length unit
+        pub const ALL: [LengthUnit; 10] = [
+            LengthUnit::Micrometers,
+            LengthUnit::Millimeters,
+            LengthUnit::Centimeters,
+            LengthUnit::Meters,
+            LengthUnit::Kilometers,
+            LengthUnit::Inches,
+            LengthUnit::Feet,
+            LengthUnit::Yards,
+            LengthUnit::Miles,
+            LengthUnit::UsSurveyFeet,
+        ];
+
+        /// Returns the unit symbol, e.g. `"ft"`
+        pub fn symbol(self) -> &'static str {
+            match self {
+                LengthUnit::Micrometers => "µm",
+                LengthUnit::Millimeters => "mm",
+                LengthUnit::Centimeters => "cm",
+                LengthUnit::Meters => "m",
+                LengthUnit::Kilometers => "km",
+                LengthUnit::Inches => "in",
+                LengthUnit::Feet => "ft",
+                LengthUnit::Yards => "yd",
+                LengthUnit::Miles => "mi",
+                LengthUnit::UsSurveyFeet => "ft_us",
+            }
+        }
+
+        /// Returns alternative spellings accepted when parsing
+        pub fn aliases(self) -> &'static [&'static str] {
+            match self {
+                LengthUnit::Micrometers => &[
+                    "um",
+                    "micrometer",
+                    "micrometers",
+                    "micrometre",
+                    "micrometres",
+                    "micron",
+                    "microns",
+                ],
+                LengthUnit::Millimeters => {
+                    &["millimeter", "millimeters", "millimetre", "millimetres"]
+                }
+                LengthUnit::Centimeters => {
+                    &["centimeter", "centimeters", "centimetre", "centimetres"]
+                }
+                LengthUnit::Meters => &["meter", "meters", "metre", "metres"],
+                LengthUnit::Kilometers => &["kilometer", "kilometers", "kilometre", "kilometres"],
+                LengthUnit::Inches => &["inch", "inches"],
+                LengthUnit::Feet => &["foot", "feet"],
+                LengthUnit::Yards => &["yard", "yards"],
+                LengthUnit::Miles => &["mile", "miles"],
+                LengthUnit::UsSurveyFeet => &[
+                    "survey foot",
+                    "survey feet",
+                    "us survey foot",
+                    "us survey feet",
+                ],
+            }
+        }
+
+        /// Returns how many meters one of this unit represents
+        pub fn meters_per_unit(self) -> f64 {
+            match self {
+                LengthUnit::Micrometers => 1e-6,
+                LengthUnit::Millimeters => 0.001,
+                LengthUnit::Centimeters => 0.01,
+                LengthUnit::Meters => 1.0,
+                LengthUnit::Kilometers => 1000.0,
+                LengthUnit::Inches => 0.0254,
+                LengthUnit::Feet => 0.3048,
+                LengthUnit::Yards => 0.9144,
+                LengthUnit::Miles => 1609.344,
+                LengthUnit::UsSurveyFeet => US_SURVEY_FOOT_M,
+            }
+        }
+
+        /// Returns [`meters_per_unit`](Self::meters_per_unit) as an exact
+        /// fraction (numerator, denominator)
+        pub fn meters_per_unit_ratio(self) -> (i64, i64) {
+            match self {
+                LengthUnit::Micrometers => (1, 1_000_000),
+                LengthUnit::Millimeters => (1, 1000),
+                LengthUnit::Centimeters => (1, 100),
+                LengthUnit::Meters => (1, 1),
+                LengthUnit::Kilometers => (1000, 1),
+                LengthUnit::Inches => (127, 5000),
+                LengthUnit::Feet => (381, 1250),
+                LengthUnit::Yards => (1143, 1250),
+                LengthUnit::Miles => (201_168, 125),
+                LengthUnit::UsSurveyFeet => (1200, 3937),
+            }
+        }
+    }
+
+    impl FromStr for LengthUnit {
+        type Err = Error;
+
+        fn from_str(s: &str) -> Result<Self, Self::Err> {
+            let symbol = crate::normalize_unit_symbol(s);
+            let symbol = symbol.as_ref();
+            LengthUnit::ALL
+                .into_iter()
+                .find(|unit| unit.symbol() == symbol || unit.aliases().contains(&symbol))
+                .ok_or_else(|| Error::UnknownUnit(s.to_string()))
+        }
+    }
+
+    /// The length category as a [`CategoryConverter`](crate::CategoryConverter)
+    #[derive(Debug, Clone, Copy, Default)]
+    pub struct LengthCategory;
+
+    impl crate::CategoryConverter for LengthCategory {
+        fn category(&self) -> crate::Category {
+            crate::Category::Length
+        }
+    }
+
+    /// Length of the US survey foot in meters (1200/3937)
+    ///
+    /// About 2 ppm longer than the international foot; still found in older
+    /// US surveying data.
+    pub const US_SURVEY_FOOT_M: f64 = 1200.0 / 3937.0;
+
     /// Converts meters to feet
     ///
     /// # Arguments
@@ -44,6 +214,30 @@ pub mod length {
         meters * 3.28084
     }
 
+    /// Converts a slice of meters to feet in parallel
+    ///
+    /// Requires the `rayon` feature. Produces exactly the same values as
+    /// calling [`meters_to_feet`] on each element, spread across threads for
+    /// very large inputs.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use conversion_lib::length;
+    ///
+    /// let feet = length::meters_to_feet_par(&[1.0, 2.0]);
+    /// assert_eq!(feet, vec![length::meters_to_feet(1.0), length::meters_to_feet(2.0)]);
+    /// ```
+    #[cfg(feature = "rayon")]
+    pub fn meters_to_feet_par(input: &[f64]) -> Vec<f64> {
+        use rayon::prelude::*;
+
+        input
+            .par_iter()
+            .map(|&meters| meters_to_feet(meters))
+            .collect()
+    }
+
     /// Converts feet to meters
     ///
     /// # Arguments
@@ -83,144 +277,9474 @@ pub mod length {
     pub fn miles_to_km(miles: f64) -> f64 {
         miles * 1.60934
     }
-}
 
-/// Temperature conversion functions
-pub mod temperature {
-    /// Converts Celsius to Fahrenheit
+    /// Returns the display name of a length unit in the given language
     ///
-    /// # Arguments
+    /// Only names are localized; symbols such as `"m"` are universal.
     ///
-    /// * `celsius` - Temperature in Celsius
+    /// # Examples
     ///
-    /// # Returns
+    /// ```
+    /// use conversion_lib::length::{self, LengthUnit};
+    /// use conversion_lib::Lang;
     ///
-    /// Temperature in Fahrenheit
+    /// assert_eq!(length::unit_name(LengthUnit::Meters, Lang::Fr), "mètres");
+    /// ```
+    pub fn unit_name(unit: LengthUnit, lang: Lang) -> &'static str {
+        match (unit, lang) {
+            (LengthUnit::Micrometers, Lang::En) => "micrometers",
+            (LengthUnit::Micrometers, Lang::De) => "Mikrometer",
+            (LengthUnit::Micrometers, Lang::Fr) => "micromètres",
+            (LengthUnit::Millimeters, Lang::En) => "millimeters",
+            (LengthUnit::Millimeters, Lang::De) => "Millimeter",
+            (LengthUnit::Millimeters, Lang::Fr) => "millimètres",
+            (LengthUnit::Centimeters, Lang::En) => "centimeters",
+            (LengthUnit::Centimeters, Lang::De) => "Zentimeter",
+            (LengthUnit::Centimeters, Lang::Fr) => "centimètres",
+            (LengthUnit::Meters, Lang::En) => "meters",
+            (LengthUnit::Meters, Lang::De) => "Meter",
+            (LengthUnit::Meters, Lang::Fr) => "mètres",
+            (LengthUnit::Kilometers, Lang::En) => "kilometers",
+            (LengthUnit::Kilometers, Lang::De) => "Kilometer",
+            (LengthUnit::Kilometers, Lang::Fr) => "kilomètres",
+            (LengthUnit::Inches, Lang::En) => "inches",
+            (LengthUnit::Inches, Lang::De) => "Zoll",
+            (LengthUnit::Inches, Lang::Fr) => "pouces",
+            (LengthUnit::Feet, Lang::En) => "feet",
+            (LengthUnit::Feet, Lang::De) => "Fuß",
+            (LengthUnit::Feet, Lang::Fr) => "pieds",
+            (LengthUnit::Yards, Lang::En) => "yards",
+            (LengthUnit::Yards, Lang::De) => "Yards",
+            (LengthUnit::Yards, Lang::Fr) => "verges",
+            (LengthUnit::Miles, Lang::En) => "miles",
+            (LengthUnit::Miles, Lang::De) => "Meilen",
+            (LengthUnit::Miles, Lang::Fr) => "milles",
+            (LengthUnit::UsSurveyFeet, Lang::En) => "US survey feet",
+            (LengthUnit::UsSurveyFeet, Lang::De) => "US-Vermessungsfuß",
+            (LengthUnit::UsSurveyFeet, Lang::Fr) => "pieds d'arpentage américains",
+        }
+    }
+
+    /// Converts a length between any two supported units
     ///
     /// # Examples
     ///
     /// ```
-    /// use conversion_lib::temperature;
+    /// use conversion_lib::length::{self, LengthUnit};
     ///
-    /// let fahrenheit = temperature::celsius_to_fahrenheit(0.0);
-    /// assert_eq!(fahrenheit, 32.0);
+    /// let meters = length::convert(1.0, LengthUnit::Kilometers, LengthUnit::Meters);
+    /// assert_eq!(meters, 1000.0);
     /// ```
-    pub fn celsius_to_fahrenheit(celsius: f64) -> f64 {
-        (celsius * 9.0 / 5.0) + 32.0
+    pub fn convert(value: f64, from: LengthUnit, to: LengthUnit) -> f64 {
+        value * from.meters_per_unit() / to.meters_per_unit()
     }
 
-    /// Converts Fahrenheit to Celsius
-    pub fn fahrenheit_to_celsius(fahrenheit: f64) -> f64 {
-        (fahrenheit - 32.0) * 5.0 / 9.0
+    /// Converts a slice of lengths, returning the inputs and results side by side
+    ///
+    /// Both vectors are filled in a single pass, which is handy for plotting
+    /// the original and converted series together.
+    ///
+    /// # Returns
+    ///
+    /// `(inputs, converted)`, where `inputs` is a copy of `input`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use conversion_lib::length::{self, LengthUnit};
+    ///
+    /// let (km, m) = length::convert_split(&[1.0, 2.5], LengthUnit::Kilometers, LengthUnit::Meters);
+    /// assert_eq!(km, vec![1.0, 2.5]);
+    /// assert_eq!(m, vec![1000.0, 2500.0]);
+    /// ```
+    pub fn convert_split(input: &[f64], from: LengthUnit, to: LengthUnit) -> (Vec<f64>, Vec<f64>) {
+        let factor = from.meters_per_unit() / to.meters_per_unit();
+        let mut originals = Vec::with_capacity(input.len());
+        let mut converted = Vec::with_capacity(input.len());
+        for &value in input {
+            originals.push(value);
+            converted.push(value * factor);
+        }
+        (originals, converted)
     }
 
-    /// Converts Celsius to Kelvin
-    pub fn celsius_to_kelvin(celsius: f64) -> f64 {
-        celsius + 273.15
+    /// Builds a spreadsheet formula that performs the same conversion as [`convert`]
+    ///
+    /// The factor is written with full `f64` precision so the spreadsheet
+    /// reproduces the library's result. Use [`temperature::to_spreadsheet_formula`]
+    /// for the affine temperature scales.
+    ///
+    /// [`temperature::to_spreadsheet_formula`]: crate::temperature::to_spreadsheet_formula
+    ///
+    /// # Arguments
+    ///
+    /// * `cell` - Cell reference holding the input, e.g. `"A1"`
+    /// * `from` - Unit of the value in the cell
+    /// * `to` - Unit the formula should produce
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use conversion_lib::length::{self, LengthUnit};
+    ///
+    /// let formula = length::to_spreadsheet_formula("A1", LengthUnit::Meters, LengthUnit::Feet);
+    /// assert_eq!(formula, "=A1*3.280839895013123");
+    /// ```
+    pub fn to_spreadsheet_formula(cell: &str, from: LengthUnit, to: LengthUnit) -> String {
+        if from == to {
+            return format!("={}", cell);
+        }
+        format!("={}*{}", cell, convert(1.0, from, to))
     }
 
-    /// Converts Kelvin to Celsius
-    pub fn kelvin_to_celsius(kelvin: f64) -> f64 {
-        kelvin - 273.15
+    /// Converts a length given as a fraction, returning an exact reduced fraction
+    ///
+    /// Every length factor is an exact decimal, so the result is the exact
+    /// value of `value_num / value_den` in the target unit. The denominator of
+    /// the result is always positive.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `value_den` is zero or the reduced result does not fit in `i64`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use conversion_lib::length::{self, LengthUnit};
+    ///
+    /// let yard = length::convert_rational(3, 1, LengthUnit::Feet, LengthUnit::Meters);
+    /// assert_eq!(yard, (1143, 1250));
+    /// ```
+    pub fn convert_rational(
+        value_num: i64,
+        value_den: i64,
+        from: LengthUnit,
+        to: LengthUnit,
+    ) -> (i64, i64) {
+        assert!(value_den != 0, "denominator must not be zero");
+
+        let (from_num, from_den) = from.meters_per_unit_ratio();
+        let (to_num, to_den) = to.meters_per_unit_ratio();
+        let mut num = i128::from(value_num) * i128::from(from_num) * i128::from(to_den);
+        let mut den = i128::from(value_den) * i128::from(from_den) * i128::from(to_num);
+
+        let divisor = gcd(num, den);
+        num /= divisor;
+        den /= divisor;
+        if den < 0 {
+            num = -num;
+            den = -den;
+        }
+
+        let fits = |n: i128| i64::try_from(n).expect("result does not fit in i64");
+        (fits(num), fits(den))
     }
-}
 
-/// Pressure conversion functions
-pub mod pressure {
-    /// Converts bar to PSI (pounds per square inch)
+    /// Converts a rational length exactly, whatever its magnitude
     ///
-    /// # Arguments
+    /// Requires the `num-rational` feature. The value and result are
+    /// arbitrary-precision fractions, so nothing can overflow and the result
+    /// is always reduced.
     ///
-    /// * `bar` - Pressure in bar
+    /// # Examples
     ///
-    /// # Returns
+    /// ```
+    /// use conversion_lib::length::{self, LengthUnit};
+    /// use num_rational::BigRational;
     ///
-    /// Pressure in PSI
-    pub fn bar_to_psi(bar: f64) -> f64 {
-        bar * 14.5038
+    /// let mile = BigRational::from_integer(1.into());
+    /// let meters = length::convert_exact(&mile, LengthUnit::Miles, LengthUnit::Meters);
+    /// assert_eq!(meters, BigRational::new(201168.into(), 125.into()));
+    /// ```
+    #[cfg(feature = "num-rational")]
+    pub fn convert_exact(
+        value: &num_rational::BigRational,
+        from: LengthUnit,
+        to: LengthUnit,
+    ) -> num_rational::BigRational {
+        use num_bigint::BigInt;
+        use num_rational::BigRational;
+
+        let big = |(num, den): (i64, i64)| BigRational::new(BigInt::from(num), BigInt::from(den));
+        value * big(from.meters_per_unit_ratio()) / big(to.meters_per_unit_ratio())
     }
 
-    /// Converts PSI to bar
-    pub fn psi_to_bar(psi: f64) -> f64 {
-        psi * 0.0689476
+    fn gcd(a: i128, b: i128) -> i128 {
+        let (mut a, mut b) = (a.abs(), b.abs());
+        while b != 0 {
+            (a, b) = (b, a % b);
+        }
+        a
     }
 
-    /// Converts Pascal to bar
-    pub fn pascal_to_bar(pascal: f64) -> f64 {
-        pascal / 100000.0
+    /// Converts a length and describes each step through the base unit
+    ///
+    /// Intended for teaching: the output shows the value in meters before it
+    /// is converted to the target unit. Values are rounded to 10 decimal
+    /// places for display.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use conversion_lib::length::{self, LengthUnit};
+    ///
+    /// let trace = length::convert_verbose(3.0, LengthUnit::Feet, LengthUnit::Kilometers);
+    /// assert_eq!(trace, "3 ft = 0.9144 m → 0.0009144 km");
+    /// ```
+    pub fn convert_verbose(value: f64, from: LengthUnit, to: LengthUnit) -> String {
+        let meters = value * from.meters_per_unit();
+        let result = convert(value, from, to);
+        format!(
+            "{} {} = {} m → {} {}",
+            display_rounded(value),
+            from.symbol(),
+            display_rounded(meters),
+            display_rounded(result),
+            to.symbol()
+        )
     }
 
-    /// Converts bar to Pascal
-    pub fn bar_to_pascal(bar: f64) -> f64 {
-        bar * 100000.0
+    /// Formats a value with at most 10 decimal places and no trailing zeros
+    fn display_rounded(value: f64) -> String {
+        let formatted = format!("{:.10}", value);
+        let trimmed = formatted.trim_end_matches('0').trim_end_matches('.');
+        if trimmed == "-0" {
+            "0".to_string()
+        } else {
+            trimmed.to_string()
+        }
     }
-}
 
-/// Mass conversion functions
-pub mod mass {
-    /// Converts kilograms to pounds
-    pub fn kg_to_pounds(kg: f64) -> f64 {
-        kg * 2.20462
+    /// Converts a length and returns an interval guaranteed to contain the exact result
+    ///
+    /// Meant for verification contexts. The conversion factors are exact decimal
+    /// definitions that are not always representable as `f64`, and every
+    /// floating point operation rounds, so each step is widened outward by one
+    /// ulp. The returned bounds therefore enclose the result of the exact
+    /// real-number computation.
+    ///
+    /// # Returns
+    ///
+    /// A `(lo, hi)` pair with `lo <= hi`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use conversion_lib::length::{self, LengthUnit};
+    ///
+    /// let (lo, hi) = length::convert_interval(5.0, LengthUnit::Miles, LengthUnit::Feet);
+    /// assert!(lo <= 26400.0 && 26400.0 <= hi);
+    /// ```
+    pub fn convert_interval(value: f64, from: LengthUnit, to: LengthUnit) -> (f64, f64) {
+        let widen = |x: f64| (x.next_down(), x.next_up());
+        let (from_lo, from_hi) = widen(from.meters_per_unit());
+        let (to_lo, to_hi) = widen(to.meters_per_unit());
+
+        let meters = outward(&[value * from_lo, value * from_hi]);
+        outward(&[
+            meters.0 / to_lo,
+            meters.0 / to_hi,
+            meters.1 / to_lo,
+            meters.1 / to_hi,
+        ])
     }
 
-    /// Converts pounds to kilograms
-    pub fn pounds_to_kg(pounds: f64) -> f64 {
-        pounds * 0.453592
+    /// Returns the hull of the candidates, widened by one ulp on each side
+    fn outward(candidates: &[f64]) -> (f64, f64) {
+        let lo = candidates.iter().copied().fold(f64::INFINITY, f64::min);
+        let hi = candidates.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+        (lo.next_down(), hi.next_up())
     }
 
-    /// Converts metric tons (tonnes) to short tons
-    pub fn tonnes_to_tons(tonnes: f64) -> f64 {
-        tonnes * 1.10231
+    /// Interpolates linearly between two lengths
+    ///
+    /// Both lengths are normalized to meters first, so the endpoints may use
+    /// different units.
+    ///
+    /// # Arguments
+    ///
+    /// * `a` - Length at `t = 0`
+    /// * `b` - Length at `t = 1`
+    /// * `t` - Interpolation parameter between 0 and 1
+    ///
+    /// # Returns
+    ///
+    /// The interpolated length in the unit of `a`
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::CategoryMismatch`] if either quantity is not a length
+    /// and [`Error::InvalidInput`] if `t` is outside 0 – 1
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use conversion_lib::length::{self, LengthUnit};
+    /// use conversion_lib::Quantity;
+    ///
+    /// let a = Quantity::new(0.0, LengthUnit::Meters);
+    /// let b = Quantity::new(1.0, LengthUnit::Kilometers);
+    /// assert_eq!(length::lerp(a, b, 0.25).unwrap(), Quantity::new(250.0, LengthUnit::Meters));
+    /// ```
+    pub fn lerp(a: Quantity, b: Quantity, t: f64) -> Result<Quantity, Error> {
+        check_same_category(a.unit, Category::Length.base_unit())?;
+        check_same_category(b.unit, Category::Length.base_unit())?;
+        if !(0.0..=1.0).contains(&t) {
+            return Err(Error::InvalidInput(format!(
+                "t must be between 0 and 1, got {}",
+                t
+            )));
+        }
+
+        let (start, end) = (a.base_value(), b.base_value());
+        Ok(Quantity {
+            value: a.unit.from_base(start + (end - start) * t),
+            unit: a.unit,
+        })
     }
 
-    /// Converts short tons to metric tons (tonnes)
-    pub fn tons_to_tonnes(tons: f64) -> f64 {
-        tons * 0.907185
+    /// Builds forward and inverse conversion closures for a unit pair
+    ///
+    /// The factor is resolved once, so the closures avoid dispatching on the
+    /// units for every call in a hot loop.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use conversion_lib::length::{self, LengthUnit};
+    ///
+    /// let (to_feet, to_inches) = length::conversion_pair(LengthUnit::Inches, LengthUnit::Feet);
+    /// assert!((to_feet(24.0) - 2.0).abs() < 1e-9);
+    /// assert!((to_inches(2.0) - 24.0).abs() < 1e-9);
+    /// ```
+    pub fn conversion_pair(
+        from: LengthUnit,
+        to: LengthUnit,
+    ) -> (impl Fn(f64) -> f64, impl Fn(f64) -> f64) {
+        let forward = from.meters_per_unit() / to.meters_per_unit();
+        let inverse = to.meters_per_unit() / from.meters_per_unit();
+        (move |value| value * forward, move |value| value * inverse)
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+    /// Converts a length into every supported unit at once
+    ///
+    /// [`deprecated`](crate::deprecated) units are left out.
+    ///
+    /// # Returns
+    ///
+    /// A map from unit symbol (e.g. `"ft"`) to the converted value
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use conversion_lib::length::{self, LengthUnit};
+    ///
+    /// let all = length::convert_all_map(1.0, LengthUnit::Kilometers);
+    /// assert_eq!(all["m"], 1000.0);
+    /// ```
+    pub fn convert_all_map(value: f64, from: LengthUnit) -> HashMap<&'static str, f64> {
+        LengthUnit::ALL
+            .iter()
+            .filter(|&&unit| !crate::deprecated(unit))
+            .map(|&unit| (unit.symbol(), convert(value, from, unit)))
+            .collect()
+    }
 
-    #[test]
-    fn test_length_conversions() {
-        // Test meters to feet
-        let result = length::meters_to_feet(1.0);
-        assert!((result - 3.28084).abs() < 0.0001);
+    /// Chooses the length unit that shows the most values in a readable range
+    ///
+    /// A value is readable when its magnitude in the candidate unit is between
+    /// 1 and 1000. Ties go to the unit listed first in [`LengthUnit::ALL`], so
+    /// metric units win over imperial ones of similar size. [`deprecated`]
+    /// units are never chosen. An empty dataset, or one with no readable value
+    /// in any unit, gives meters.
+    ///
+    /// [`deprecated`]: crate::deprecated
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use conversion_lib::length::{self, LengthUnit};
+    ///
+    /// let unit = length::optimal_unit_for_dataset(&[0.004, 0.012, 0.25]);
+    /// assert_eq!(unit, LengthUnit::Millimeters);
+    /// ```
+    pub fn optimal_unit_for_dataset(values_m: &[f64]) -> LengthUnit {
+        let readable = |unit: LengthUnit| {
+            values_m
+                .iter()
+                .map(|&meters| convert(meters, LengthUnit::Meters, unit).abs())
+                .filter(|value| (1.0..1000.0).contains(value))
+                .count()
+        };
 
-        // Test round trip conversion
-        let meters = 100.0;
-        let feet = length::meters_to_feet(meters);
-        let back_to_meters = length::feet_to_meters(feet);
-        assert!((meters - back_to_meters).abs() < 0.0001);
+        let mut best = (LengthUnit::Meters, 0);
+        for unit in LengthUnit::ALL
+            .into_iter()
+            .filter(|&unit| !crate::deprecated(unit))
+        {
+            let count = readable(unit);
+            if count > best.1 {
+                best = (unit, count);
+            }
+        }
+        best.0
     }
 
-    #[test]
-    fn test_temperature_conversions() {
-        // Test freezing point
-        assert_eq!(temperature::celsius_to_fahrenheit(0.0), 32.0);
-        
-        // Test boiling point
-        assert_eq!(temperature::celsius_to_fahrenheit(100.0), 212.0);
+    /// Rescales a length to the SI-prefixed unit that gives a mantissa in [1, 1000)
+    ///
+    /// The candidate units are µm, mm, m and km, so lengths below 1 µm stay in
+    /// micrometers and lengths of 1000 km or more stay in kilometers. Zero,
+    /// non-finite values and quantities that are not lengths are returned
+    /// unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use conversion_lib::length::{self, LengthUnit};
+    /// use conversion_lib::Quantity;
+    ///
+    /// let normalized = length::normalize_prefix(Quantity::new(0.0000123, LengthUnit::Meters));
+    /// assert_eq!(normalized.unit, LengthUnit::Micrometers.into());
+    /// assert!((normalized.value - 12.3).abs() < 1e-9);
+    /// ```
+    pub fn normalize_prefix(q: Quantity) -> Quantity {
+        const PREFIXED: [LengthUnit; 4] = [
+            LengthUnit::Micrometers,
+            LengthUnit::Millimeters,
+            LengthUnit::Meters,
+            LengthUnit::Kilometers,
+        ];
 
-        // Test Kelvin
-        assert_eq!(temperature::celsius_to_kelvin(0.0), 273.15);
+        let from = match q.unit {
+            Unit::Length(unit) if q.value.is_finite() && q.value != 0.0 => unit,
+            _ => return q,
+        };
+        let meters = convert(q.value, from, LengthUnit::Meters).abs();
+        let unit = PREFIXED
+            .into_iter()
+            .rev()
+            .find(|unit| meters >= unit.meters_per_unit())
+            .unwrap_or(LengthUnit::Micrometers);
+        Quantity::new(convert(q.value, from, unit), unit)
     }
 
-    #[test]
-    fn test_pressure_conversions() {
-        let bar = 1.0;
-        let psi = pressure::bar_to_psi(bar);
-        let back_to_bar = pressure::psi_to_bar(psi);
-        assert!((bar - back_to_bar).abs() < 0.0001);
+    /// Standard imperial fastener sizes as (label, size in inches)
+    pub const FASTENER_SIZES: [(&str, f64); 12] = [
+        ("1/8\"", 0.125),
+        ("3/16\"", 0.1875),
+        ("1/4\"", 0.25),
+        ("5/16\"", 0.3125),
+        ("3/8\"", 0.375),
+        ("7/16\"", 0.4375),
+        ("1/2\"", 0.5),
+        ("9/16\"", 0.5625),
+        ("5/8\"", 0.625),
+        ("3/4\"", 0.75),
+        ("7/8\"", 0.875),
+        ("1\"", 1.0),
+    ];
+
+    /// Finds the standard imperial fastener size closest to a metric length
+    ///
+    /// Useful when sourcing imperial hardware for a metric design. Sizes
+    /// outside the table snap to the smallest or largest entry.
+    ///
+    /// # Arguments
+    ///
+    /// * `meters` - Nominal diameter in meters
+    ///
+    /// # Returns
+    ///
+    /// The label of the nearest size from [`FASTENER_SIZES`], e.g. `1/4"`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use conversion_lib::length;
+    ///
+    /// assert_eq!(length::nearest_fastener(0.0127), "1/2\"");
+    /// ```
+    pub fn nearest_fastener(meters: f64) -> &'static str {
+        let inches = convert(meters, LengthUnit::Meters, LengthUnit::Inches);
+        FASTENER_SIZES
+            .iter()
+            .min_by(|a, b| (a.1 - inches).abs().total_cmp(&(b.1 - inches).abs()))
+            .map(|&(label, _)| label)
+            .expect("fastener table is not empty")
     }
 
-    #[test]
-    fn test_mass_conversions() {
-        let kg = 100.0;
-        let pounds = mass::kg_to_pounds(kg);
-        let back_to_kg = mass::pounds_to_kg(pounds);
-        assert!((kg - back_to_kg).abs() < 0.0001);
+    /// Guesses the unit of a length value whose unit is missing
+    ///
+    /// This is a best-effort heuristic for messy data, not a conversion: values
+    /// between 1 and 3 are most likely meters (room heights, door widths), and
+    /// values between 100 and 400 are most likely feet (building and site
+    /// dimensions). Anything else is considered ambiguous.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - The unlabelled length value
+    /// * `context` - The category the value is known to belong to
+    ///
+    /// # Returns
+    ///
+    /// The most likely unit, or `None` when the value is ambiguous or the
+    /// context is not a length
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use conversion_lib::length::{self, LengthUnit};
+    /// use conversion_lib::Category;
+    ///
+    /// assert_eq!(length::guess_unit(2.4, Category::Length), Some(LengthUnit::Meters));
+    /// assert_eq!(length::guess_unit(50.0, Category::Length), None);
+    /// ```
+    pub fn guess_unit(value: f64, context: Category) -> Option<LengthUnit> {
+        if context != Category::Length {
+            return None;
+        }
+
+        let magnitude = value.abs();
+        if (1.0..=3.0).contains(&magnitude) {
+            Some(LengthUnit::Meters)
+        } else if (100.0..=400.0).contains(&magnitude) {
+            Some(LengthUnit::Feet)
+        } else {
+            None
+        }
+    }
+}
+
+/// Temperature conversion functions
+pub mod temperature {
+    use crate::{check_same_category, scale_type, within_tolerance, Error, Quantity, ScaleType};
+    use std::fmt;
+    use std::str::FromStr;
+
+    /// Supported temperature scales
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    pub enum TemperatureUnit {
+        Celsius,
+        Fahrenheit,
+        Kelvin,
+        Delisle,
+    }
+
+    impl TemperatureUnit {
+        /// Every supported temperature scale
+        pub const ALL: [TemperatureUnit; 4] = [
+            TemperatureUnit::Celsius,
+            TemperatureUnit::Fahrenheit,
+            TemperatureUnit::Kelvin,
+            TemperatureUnit::Delisle,
+        ];
+
+        /// Returns the unit symbol, e.g. `"°C"`
+        pub fn symbol(self) -> &'static str {
+            match self {
+                TemperatureUnit::Celsius => "°C",
+                TemperatureUnit::Fahrenheit => "°F",
+                TemperatureUnit::Kelvin => "K",
+                TemperatureUnit::Delisle => "°De",
+            }
+        }
+
+        /// Returns alternative spellings accepted when parsing
+        pub fn aliases(self) -> &'static [&'static str] {
+            match self {
+                TemperatureUnit::Celsius => &["C", "degC", "celsius"],
+                TemperatureUnit::Fahrenheit => &["F", "degF", "fahrenheit"],
+                TemperatureUnit::Kelvin => &["kelvin"],
+                TemperatureUnit::Delisle => &["De", "degDe", "delisle"],
+            }
+        }
+
+        /// Converts a temperature on this scale to Kelvin
+        pub fn to_kelvin(self, value: f64) -> f64 {
+            match self {
+                TemperatureUnit::Celsius => celsius_to_kelvin(value),
+                TemperatureUnit::Fahrenheit => celsius_to_kelvin(fahrenheit_to_celsius(value)),
+                TemperatureUnit::Kelvin => value,
+                TemperatureUnit::Delisle => celsius_to_kelvin(delisle_to_celsius(value)),
+            }
+        }
+
+        /// Converts a temperature in Kelvin to this scale
+        pub fn from_kelvin(self, kelvin: f64) -> f64 {
+            match self {
+                TemperatureUnit::Celsius => kelvin_to_celsius(kelvin),
+                TemperatureUnit::Fahrenheit => celsius_to_fahrenheit(kelvin_to_celsius(kelvin)),
+                TemperatureUnit::Kelvin => kelvin,
+                TemperatureUnit::Delisle => celsius_to_delisle(kelvin_to_celsius(kelvin)),
+            }
+        }
+    }
+
+    impl FromStr for TemperatureUnit {
+        type Err = Error;
+
+        fn from_str(s: &str) -> Result<Self, Self::Err> {
+            let symbol = crate::normalize_unit_symbol(s);
+            let symbol = symbol.as_ref();
+            TemperatureUnit::ALL
+                .into_iter()
+                .find(|unit| unit.symbol() == symbol || unit.aliases().contains(&symbol))
+                .ok_or_else(|| Error::UnknownUnit(s.to_string()))
+        }
+    }
+
+    /// The temperature category as a [`CategoryConverter`](crate::CategoryConverter)
+    #[derive(Debug, Clone, Copy, Default)]
+    pub struct TemperatureCategory;
+
+    impl crate::CategoryConverter for TemperatureCategory {
+        fn category(&self) -> crate::Category {
+            crate::Category::Temperature
+        }
+    }
+
+    /// Converts Celsius to Fahrenheit
+    ///
+    /// # Arguments
+    ///
+    /// * `celsius` - Temperature in Celsius
+    ///
+    /// # Returns
+    ///
+    /// Temperature in Fahrenheit
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use conversion_lib::temperature;
+    ///
+    /// let fahrenheit = temperature::celsius_to_fahrenheit(0.0);
+    /// assert_eq!(fahrenheit, 32.0);
+    /// ```
+    pub fn celsius_to_fahrenheit(celsius: f64) -> f64 {
+        (celsius * 9.0 / 5.0) + 32.0
+    }
+
+    /// Converts Fahrenheit to Celsius
+    pub fn fahrenheit_to_celsius(fahrenheit: f64) -> f64 {
+        (fahrenheit - 32.0) * 5.0 / 9.0
+    }
+
+    /// Converts Celsius to Kelvin
+    pub fn celsius_to_kelvin(celsius: f64) -> f64 {
+        celsius + 273.15
+    }
+
+    /// Converts Kelvin to Celsius
+    pub fn kelvin_to_celsius(kelvin: f64) -> f64 {
+        kelvin - 273.15
+    }
+
+    /// Reasons a temperature reading is rejected
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    pub enum TemperatureError {
+        /// The reading is NaN or infinite
+        NotFinite(f64),
+        /// The reading is colder than absolute zero, in degrees Celsius
+        BelowAbsoluteZero(f64),
+    }
+
+    impl fmt::Display for TemperatureError {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            match self {
+                TemperatureError::NotFinite(value) => {
+                    write!(f, "{} is not a finite temperature", value)
+                }
+                TemperatureError::BelowAbsoluteZero(celsius) => {
+                    write!(f, "{} °C is below absolute zero", celsius)
+                }
+            }
+        }
+    }
+
+    impl std::error::Error for TemperatureError {}
+
+    /// Converts Celsius to Kelvin, rejecting readings that are not physical
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TemperatureError::NotFinite`] for NaN or infinite input and
+    /// [`TemperatureError::BelowAbsoluteZero`] below -273.15 °C
+    pub fn celsius_to_kelvin_checked(celsius: f64) -> Result<f64, TemperatureError> {
+        if !celsius.is_finite() {
+            return Err(TemperatureError::NotFinite(celsius));
+        }
+        let kelvin = celsius_to_kelvin(celsius);
+        if kelvin < 0.0 {
+            return Err(TemperatureError::BelowAbsoluteZero(celsius));
+        }
+        Ok(kelvin)
+    }
+
+    /// An absolute temperature that is known to be physical
+    ///
+    /// Construction goes through [`Kelvin::new`], so a value below absolute
+    /// zero can never exist. With the `serde` feature it serializes as a plain
+    /// number and the same check runs while deserializing.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use conversion_lib::temperature::Kelvin;
+    ///
+    /// assert_eq!(Kelvin::new(300.0).unwrap().value(), 300.0);
+    /// assert!(Kelvin::new(-1.0).is_err());
+    /// ```
+    #[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+    pub struct Kelvin(f64);
+
+    impl Kelvin {
+        /// Creates a Kelvin temperature
+        ///
+        /// # Errors
+        ///
+        /// Returns [`TemperatureError::NotFinite`] for NaN or infinite input and
+        /// [`TemperatureError::BelowAbsoluteZero`] for negative values
+        pub fn new(kelvin: f64) -> Result<Self, TemperatureError> {
+            if !kelvin.is_finite() {
+                return Err(TemperatureError::NotFinite(kelvin));
+            }
+            if kelvin < 0.0 {
+                return Err(TemperatureError::BelowAbsoluteZero(kelvin_to_celsius(
+                    kelvin,
+                )));
+            }
+            Ok(Kelvin(kelvin))
+        }
+
+        /// Returns the temperature in Kelvin
+        pub fn value(self) -> f64 {
+            self.0
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    impl serde::Serialize for Kelvin {
+        fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            serializer.serialize_f64(self.0)
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    impl<'de> serde::Deserialize<'de> for Kelvin {
+        fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            let kelvin = f64::deserialize(deserializer)?;
+            Kelvin::new(kelvin).map_err(serde::de::Error::custom)
+        }
+    }
+
+    /// Converts each Celsius reading to Kelvin, keeping failures per element
+    ///
+    /// The output has one entry per input in the same order, so bad readings
+    /// can be isolated without rejecting the whole batch.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use conversion_lib::temperature::{self, TemperatureError};
+    ///
+    /// let kelvin = temperature::celsius_to_kelvin_checked_slice(&[0.0, -300.0]);
+    /// assert_eq!(kelvin, vec![Ok(273.15), Err(TemperatureError::BelowAbsoluteZero(-300.0))]);
+    /// ```
+    pub fn celsius_to_kelvin_checked_slice(input: &[f64]) -> Vec<Result<f64, TemperatureError>> {
+        input
+            .iter()
+            .map(|&celsius| celsius_to_kelvin_checked(celsius))
+            .collect()
+    }
+
+    /// Converts a temperature between any two supported scales
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use conversion_lib::temperature::{self, TemperatureUnit};
+    ///
+    /// let celsius = temperature::convert(212.0, TemperatureUnit::Fahrenheit, TemperatureUnit::Celsius);
+    /// assert!((celsius - 100.0).abs() < 1e-9);
+    /// ```
+    pub fn convert(value: f64, from: TemperatureUnit, to: TemperatureUnit) -> f64 {
+        to.from_kelvin(from.to_kelvin(value))
+    }
+
+    /// Builds a spreadsheet formula that performs the same conversion as [`convert`]
+    ///
+    /// The formula goes through Celsius using the exact textbook constants,
+    /// e.g. `"=(A1*9/5)+32"` for Celsius to Fahrenheit.
+    ///
+    /// # Arguments
+    ///
+    /// * `cell` - Cell reference holding the input, e.g. `"A1"`
+    /// * `from` - Scale of the value in the cell
+    /// * `to` - Scale the formula should produce
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use conversion_lib::temperature::{self, TemperatureUnit};
+    ///
+    /// let formula = temperature::to_spreadsheet_formula(
+    ///     "B2",
+    ///     TemperatureUnit::Fahrenheit,
+    ///     TemperatureUnit::Kelvin,
+    /// );
+    /// assert_eq!(formula, "=((B2-32)*5/9)+273.15");
+    /// ```
+    pub fn to_spreadsheet_formula(
+        cell: &str,
+        from: TemperatureUnit,
+        to: TemperatureUnit,
+    ) -> String {
+        if from == to {
+            return format!("={}", cell);
+        }
+
+        let celsius = match from {
+            TemperatureUnit::Celsius => cell.to_string(),
+            TemperatureUnit::Fahrenheit => format!("({}-32)*5/9", cell),
+            TemperatureUnit::Kelvin => format!("{}-273.15", cell),
+            TemperatureUnit::Delisle => format!("100-{}*2/3", cell),
+        };
+        let term = if from == TemperatureUnit::Celsius {
+            celsius.clone()
+        } else {
+            format!("({})", celsius)
+        };
+        let formula = match to {
+            TemperatureUnit::Celsius => celsius,
+            TemperatureUnit::Fahrenheit => format!("({}*9/5)+32", term),
+            TemperatureUnit::Kelvin => format!("{}+273.15", term),
+            TemperatureUnit::Delisle => format!("(100-{})*3/2", term),
+        };
+        format!("={}", formula)
+    }
+
+    /// Converts every value of a 2D temperature field, preserving its shape
+    ///
+    /// Rows are converted independently, so ragged grids keep their row lengths.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use conversion_lib::temperature::{self, TemperatureUnit};
+    ///
+    /// let grid = vec![vec![273.15], vec![373.15, 0.0]];
+    /// let celsius = temperature::convert_grid(&grid, TemperatureUnit::Kelvin, TemperatureUnit::Celsius);
+    /// assert_eq!(celsius[1].len(), 2);
+    /// ```
+    pub fn convert_grid(
+        grid: &[Vec<f64>],
+        from: TemperatureUnit,
+        to: TemperatureUnit,
+    ) -> Vec<Vec<f64>> {
+        grid.iter()
+            .map(|row| row.iter().map(|&value| convert(value, from, to)).collect())
+            .collect()
+    }
+
+    /// Guesses the scale of a temperature reading whose unit is missing
+    ///
+    /// This is a best-effort heuristic for everyday readings, not a
+    /// conversion. The ranges used are:
+    ///
+    /// * −40 to below 32: Celsius (cold to warm ambient)
+    /// * 50 to 120: Fahrenheit (mild ambient to hot)
+    /// * 230 to 330: Kelvin (ambient temperatures in Kelvin)
+    ///
+    /// Anything else, including 32 to below 50 where Celsius and Fahrenheit
+    /// readings overlap, is considered ambiguous.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use conversion_lib::temperature::{self, TemperatureUnit};
+    ///
+    /// assert_eq!(temperature::guess_scale(77.0), Some(TemperatureUnit::Fahrenheit));
+    /// assert_eq!(temperature::guess_scale(40.0), None);
+    /// ```
+    pub fn guess_scale(value: f64) -> Option<TemperatureUnit> {
+        if (-40.0..32.0).contains(&value) {
+            Some(TemperatureUnit::Celsius)
+        } else if (50.0..=120.0).contains(&value) {
+            Some(TemperatureUnit::Fahrenheit)
+        } else if (230.0..=330.0).contains(&value) {
+            Some(TemperatureUnit::Kelvin)
+        } else {
+            None
+        }
+    }
+
+    /// Converts Celsius to Delisle
+    ///
+    /// The Delisle scale runs backwards: water boils at 0 °De and freezes at
+    /// 150 °De, so Delisle values increase as the temperature decreases.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use conversion_lib::temperature;
+    ///
+    /// assert_eq!(temperature::celsius_to_delisle(100.0), 0.0);
+    /// assert_eq!(temperature::celsius_to_delisle(0.0), 150.0);
+    /// ```
+    pub fn celsius_to_delisle(celsius: f64) -> f64 {
+        (100.0 - celsius) * 3.0 / 2.0
+    }
+
+    /// Converts Delisle to Celsius
+    pub fn delisle_to_celsius(delisle: f64) -> f64 {
+        100.0 - delisle * 2.0 / 3.0
+    }
+
+    /// Checks whether a temperature lies inside a thermostat deadband
+    ///
+    /// The deadband is the full width of the band centred on the setpoint, so
+    /// a 2 °F deadband accepts readings within ±1 °F. It is a temperature
+    /// difference: only the size of its degree is used, never the scale's
+    /// offset. The bounds count as inside.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::CategoryMismatch`] if any quantity is not a temperature
+    /// and [`Error::InvalidInput`] if the deadband is negative
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use conversion_lib::temperature::{self, TemperatureUnit};
+    /// use conversion_lib::Quantity;
+    ///
+    /// let inside = temperature::within_deadband(
+    ///     Quantity::new(20.4, TemperatureUnit::Celsius),
+    ///     Quantity::new(20.0, TemperatureUnit::Celsius),
+    ///     Quantity::new(2.0, TemperatureUnit::Fahrenheit),
+    /// );
+    /// assert_eq!(inside, Ok(true));
+    /// ```
+    pub fn within_deadband(
+        measured: Quantity,
+        setpoint: Quantity,
+        deadband: Quantity,
+    ) -> Result<bool, Error> {
+        check_same_category(measured.unit, TemperatureUnit::Kelvin.into())?;
+        if deadband.value < 0.0 {
+            return Err(Error::InvalidInput(format!(
+                "deadband {} must not be negative",
+                deadband
+            )));
+        }
+        let half_band = Quantity::new(deadband.value / 2.0, deadband.unit);
+        within_tolerance(measured, setpoint, half_band)
+    }
+
+    /// Converts a Celsius reading to a scaled 16-bit integer, as stored in PLC registers
+    ///
+    /// The reading is multiplied by `scale`, rounded to the nearest integer
+    /// (halves away from zero) and saturated at the `i16` bounds, so
+    /// `21.37 °C` at scale 10 becomes `214`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidInput`] if `celsius` is NaN or infinite
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use conversion_lib::temperature;
+    ///
+    /// assert_eq!(temperature::celsius_to_fixed_i16(21.37, 10), Ok(214));
+    /// assert_eq!(temperature::celsius_to_fixed_i16(5000.0, 10), Ok(i16::MAX));
+    /// ```
+    pub fn celsius_to_fixed_i16(celsius: f64, scale: i32) -> Result<i16, Error> {
+        if !celsius.is_finite() {
+            return Err(Error::InvalidInput(format!(
+                "{} is not a finite temperature",
+                celsius
+            )));
+        }
+        let scaled = (celsius * f64::from(scale)).round();
+        Ok(scaled.clamp(f64::from(i16::MIN), f64::from(i16::MAX)) as i16)
+    }
+
+    /// Divides one absolute temperature by another
+    ///
+    /// Ratios only make sense on absolute scales: 20 °C is not twice as hot
+    /// as 10 °C, while 300 K really is twice 150 K. The operands may use
+    /// different absolute units.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::CategoryMismatch`] if either operand is not a
+    /// temperature, [`Error::RatioRequiresAbsoluteScale`] if either uses an
+    /// interval scale such as Celsius and [`Error::DivisionByZero`] if `b` is
+    /// absolute zero
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use conversion_lib::temperature::{self, TemperatureUnit};
+    /// use conversion_lib::Quantity;
+    ///
+    /// let hot = Quantity::new(600.0, TemperatureUnit::Kelvin);
+    /// let cold = Quantity::new(300.0, TemperatureUnit::Kelvin);
+    /// assert_eq!(temperature::ratio(hot, cold).unwrap(), 2.0);
+    ///
+    /// let celsius = Quantity::new(20.0, TemperatureUnit::Celsius);
+    /// assert!(temperature::ratio(celsius, cold).is_err());
+    /// ```
+    pub fn ratio(a: Quantity, b: Quantity) -> Result<f64, Error> {
+        for unit in [a.unit, b.unit] {
+            check_same_category(unit, TemperatureUnit::Kelvin.into())?;
+            if scale_type(unit) == ScaleType::Interval {
+                return Err(Error::RatioRequiresAbsoluteScale(unit));
+            }
+        }
+
+        let denominator = b.base_value();
+        if denominator == 0.0 {
+            return Err(Error::DivisionByZero);
+        }
+        Ok(a.base_value() / denominator)
+    }
+}
+
+/// Pressure conversion functions
+///
+/// The unit conversions preserve sign so gauge pressures and pressure
+/// differences convert correctly. `hoop_stress` treats its inputs as
+/// magnitudes.
+pub mod pressure {
+    use crate::Error;
+    use std::str::FromStr;
+
+    /// Supported pressure units
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    pub enum PressureUnit {
+        Pascal,
+        Bar,
+        Psi,
+    }
+
+    impl PressureUnit {
+        /// Every supported pressure unit
+        pub const ALL: [PressureUnit; 3] =
+            [PressureUnit::Pascal, PressureUnit::Bar, PressureUnit::Psi];
+
+        /// Returns the unit symbol, e.g. `"psi"`
+        pub fn symbol(self) -> &'static str {
+            match self {
+                PressureUnit::Pascal => "Pa",
+                PressureUnit::Bar => "bar",
+                PressureUnit::Psi => "psi",
+            }
+        }
+
+        /// Returns alternative spellings accepted when parsing
+        pub fn aliases(self) -> &'static [&'static str] {
+            match self {
+                PressureUnit::Pascal => &["pascal", "pascals"],
+                PressureUnit::Bar => &["bars"],
+                PressureUnit::Psi => &["PSI"],
+            }
+        }
+
+        /// Returns how many Pascal one of this unit represents
+        pub fn pascals_per_unit(self) -> f64 {
+            match self {
+                PressureUnit::Pascal => 1.0,
+                PressureUnit::Bar => 100000.0,
+                PressureUnit::Psi => 6894.757293168361,
+            }
+        }
+    }
+
+    impl FromStr for PressureUnit {
+        type Err = Error;
+
+        fn from_str(s: &str) -> Result<Self, Self::Err> {
+            let symbol = crate::normalize_unit_symbol(s);
+            let symbol = symbol.as_ref();
+            PressureUnit::ALL
+                .into_iter()
+                .find(|unit| unit.symbol() == symbol || unit.aliases().contains(&symbol))
+                .ok_or_else(|| Error::UnknownUnit(s.to_string()))
+        }
+    }
+
+    /// The pressure category as a [`CategoryConverter`](crate::CategoryConverter)
+    #[derive(Debug, Clone, Copy, Default)]
+    pub struct PressureCategory;
+
+    impl crate::CategoryConverter for PressureCategory {
+        fn category(&self) -> crate::Category {
+            crate::Category::Pressure
+        }
+    }
+
+    /// Converts bar to PSI (pounds per square inch)
+    ///
+    /// # Arguments
+    ///
+    /// * `bar` - Pressure in bar
+    ///
+    /// # Returns
+    ///
+    /// Pressure in PSI
+    pub fn bar_to_psi(bar: f64) -> f64 {
+        bar * 14.5038
+    }
+
+    /// Converts PSI to bar
+    pub fn psi_to_bar(psi: f64) -> f64 {
+        psi * 0.0689476
+    }
+
+    /// Converts Pascal to bar
+    pub fn pascal_to_bar(pascal: f64) -> f64 {
+        pascal / 100000.0
+    }
+
+    /// Converts bar to Pascal
+    pub fn bar_to_pascal(bar: f64) -> f64 {
+        bar * 100000.0
+    }
+
+    /// Calculates the hoop stress in a thin-walled pressure vessel
+    ///
+    /// Uses the thin-wall approximation σ = P·r / t, which is suitable for quick
+    /// checks when the wall is thin compared to the radius. All inputs are
+    /// magnitudes.
+    ///
+    /// # Arguments
+    ///
+    /// * `pressure_pa` - Internal gauge pressure in Pascal
+    /// * `radius_m` - Inner radius of the vessel in meters
+    /// * `thickness_m` - Wall thickness in meters
+    ///
+    /// # Returns
+    ///
+    /// Hoop stress in Pascal
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidInput`] if the pressure or radius is negative, the
+    /// thickness is not positive, or any input is not finite
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use conversion_lib::pressure;
+    ///
+    /// let stress = pressure::hoop_stress(1_000_000.0, 0.5, 0.01).unwrap();
+    /// assert_eq!(stress, 50_000_000.0);
+    /// ```
+    pub fn hoop_stress(pressure_pa: f64, radius_m: f64, thickness_m: f64) -> Result<f64, Error> {
+        for (name, value) in [("pressure", pressure_pa), ("radius", radius_m)] {
+            if !(value.is_finite() && value >= 0.0) {
+                return Err(Error::InvalidInput(format!(
+                    "{} must be finite and non-negative, got {}",
+                    name, value
+                )));
+            }
+        }
+        if !(thickness_m.is_finite() && thickness_m > 0.0) {
+            return Err(Error::InvalidInput(format!(
+                "wall thickness must be finite and positive, got {}",
+                thickness_m
+            )));
+        }
+        Ok(pressure_pa * radius_m / thickness_m)
+    }
+
+    /// Maps a pressure to an RGB color for a gauge widget
+    ///
+    /// The color runs from green at zero through yellow at half of `max_pa`
+    /// to red at `max_pa`. Pressures above the maximum, and NaN readings, are
+    /// red; negative pressures are green.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `max_pa` is not positive
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use conversion_lib::pressure;
+    ///
+    /// assert_eq!(pressure::to_gauge_color(5.0e5, 1.0e6), (255, 255, 0));
+    /// ```
+    pub fn to_gauge_color(value_pa: f64, max_pa: f64) -> (u8, u8, u8) {
+        assert!(max_pa > 0.0, "maximum pressure must be positive");
+
+        let fraction = if value_pa.is_nan() {
+            1.0
+        } else {
+            (value_pa / max_pa).clamp(0.0, 1.0)
+        };
+        let red = (fraction * 2.0).min(1.0) * 255.0;
+        let green = ((1.0 - fraction) * 2.0).min(1.0) * 255.0;
+        (red.round() as u8, green.round() as u8, 0)
+    }
+}
+
+/// Mass conversion functions
+///
+/// The unit conversions preserve sign so mass differences (e.g. a loss
+/// between two weighings) convert correctly.
+pub mod mass {
+    use crate::{check_same_category, Category, Error, Quantity};
+    use std::str::FromStr;
+
+    /// Supported mass units
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    pub enum MassUnit {
+        Grams,
+        Kilograms,
+        Pounds,
+        Tonnes,
+        Tons,
+    }
+
+    impl MassUnit {
+        /// Every supported mass unit
+        pub const ALL: [MassUnit; 5] = [
+            MassUnit::Grams,
+            MassUnit::Kilograms,
+            MassUnit::Pounds,
+            MassUnit::Tonnes,
+            MassUnit::Tons,
+        ];
+
+        /// Returns the unit symbol, e.g. `"lb"`
+        pub fn symbol(self) -> &'static str {
+            match self {
+                MassUnit::Grams => "g",
+                MassUnit::Kilograms => "kg",
+                MassUnit::Pounds => "lb",
+                MassUnit::Tonnes => "t",
+                MassUnit::Tons => "ton",
+            }
+        }
+
+        /// Returns alternative spellings accepted when parsing
+        pub fn aliases(self) -> &'static [&'static str] {
+            match self {
+                MassUnit::Grams => &["gram", "grams"],
+                MassUnit::Kilograms => &["kilogram", "kilograms", "kgs"],
+                MassUnit::Pounds => &["lbs", "pound", "pounds"],
+                MassUnit::Tonnes => &["tonne", "tonnes"],
+                MassUnit::Tons => &["tons", "short ton", "short tons"],
+            }
+        }
+
+        /// Returns how many kilograms one of this unit represents
+        pub fn kilograms_per_unit(self) -> f64 {
+            match self {
+                MassUnit::Grams => 0.001,
+                MassUnit::Kilograms => 1.0,
+                MassUnit::Pounds => 0.45359237,
+                MassUnit::Tonnes => 1000.0,
+                MassUnit::Tons => 907.18474,
+            }
+        }
+    }
+
+    /// Picks the most readable metric unit for a mass
+    ///
+    /// Masses below 1 kg are shown in grams and masses of 1000 kg or more in
+    /// tonnes; everything else, including zero and NaN, stays in kilograms.
+    ///
+    /// # Returns
+    ///
+    /// The mass expressed in the chosen unit together with that unit
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use conversion_lib::mass::{self, MassUnit};
+    ///
+    /// assert_eq!(mass::humanize(5000.0), (5.0, MassUnit::Tonnes));
+    /// ```
+    pub fn humanize(kg: f64) -> (f64, MassUnit) {
+        let magnitude = kg.abs();
+        let unit = if magnitude > 0.0 && magnitude < 1.0 {
+            MassUnit::Grams
+        } else if magnitude >= 1000.0 {
+            MassUnit::Tonnes
+        } else {
+            MassUnit::Kilograms
+        };
+        (kg / unit.kilograms_per_unit(), unit)
+    }
+
+    impl FromStr for MassUnit {
+        type Err = Error;
+
+        fn from_str(s: &str) -> Result<Self, Self::Err> {
+            let symbol = crate::normalize_unit_symbol(s);
+            let symbol = symbol.as_ref();
+            MassUnit::ALL
+                .into_iter()
+                .find(|unit| unit.symbol() == symbol || unit.aliases().contains(&symbol))
+                .ok_or_else(|| Error::UnknownUnit(s.to_string()))
+        }
+    }
+
+    /// The mass category as a [`CategoryConverter`](crate::CategoryConverter)
+    #[derive(Debug, Clone, Copy, Default)]
+    pub struct MassCategory;
+
+    impl crate::CategoryConverter for MassCategory {
+        fn category(&self) -> crate::Category {
+            crate::Category::Mass
+        }
+    }
+
+    /// Converts kilograms to pounds
+    pub fn kg_to_pounds(kg: f64) -> f64 {
+        kg * 2.20462
+    }
+
+    /// Converts pounds to kilograms
+    pub fn pounds_to_kg(pounds: f64) -> f64 {
+        pounds * 0.453592
+    }
+
+    /// Converts metric tons (tonnes) to short tons
+    pub fn tonnes_to_tons(tonnes: f64) -> f64 {
+        tonnes * 1.10231
+    }
+
+    /// Converts short tons to metric tons (tonnes)
+    pub fn tons_to_tonnes(tons: f64) -> f64 {
+        tons * 0.907185
+    }
+
+    /// Density of water at 20 °C in kg/m³
+    pub const WATER_DENSITY_20C: f64 = 998.2;
+
+    /// Density of liquid water in kg/m³ at 1 atm, as (temperature °C, density)
+    pub const WATER_DENSITY_TABLE: [(f64, f64); 14] = [
+        (0.0, 999.84),
+        (4.0, 999.97),
+        (10.0, 999.70),
+        (15.0, 999.10),
+        (20.0, WATER_DENSITY_20C),
+        (25.0, 997.05),
+        (30.0, 995.65),
+        (40.0, 992.22),
+        (50.0, 988.04),
+        (60.0, 983.20),
+        (70.0, 977.76),
+        (80.0, 971.80),
+        (90.0, 965.31),
+        (100.0, 958.35),
+    ];
+
+    /// Calculates the mass of a volume of water at 20 °C
+    ///
+    /// # Arguments
+    ///
+    /// * `volume_l` - Volume in liters
+    ///
+    /// # Returns
+    ///
+    /// Mass in kilograms
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use conversion_lib::mass;
+    ///
+    /// assert!((mass::water_mass(1000.0) - 998.2).abs() < 1e-9);
+    /// ```
+    pub fn water_mass(volume_l: f64) -> f64 {
+        volume_l / 1000.0 * WATER_DENSITY_20C
+    }
+
+    /// Calculates the mass of a volume of water at a given temperature
+    ///
+    /// The density is interpolated linearly from [`WATER_DENSITY_TABLE`].
+    ///
+    /// # Arguments
+    ///
+    /// * `volume_l` - Volume in liters
+    /// * `temp_c` - Water temperature in Celsius
+    ///
+    /// # Returns
+    ///
+    /// Mass in kilograms
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidInput`] if `temp_c` is outside 0 – 100 °C or NaN
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use conversion_lib::mass;
+    ///
+    /// assert!((mass::water_mass_at(1000.0, 4.0).unwrap() - 1000.0).abs() < 0.1);
+    /// assert!(mass::water_mass_at(1000.0, 120.0).is_err());
+    /// ```
+    pub fn water_mass_at(volume_l: f64, temp_c: f64) -> Result<f64, Error> {
+        let pair = WATER_DENSITY_TABLE
+            .windows(2)
+            .find(|pair| (pair[0].0..=pair[1].0).contains(&temp_c))
+            .ok_or_else(|| {
+                Error::InvalidInput(format!(
+                    "water density table covers 0 to 100 °C, got {}",
+                    temp_c
+                ))
+            })?;
+        let ((t0, d0), (t1, d1)) = (pair[0], pair[1]);
+        let density = d0 + (d1 - d0) * (temp_c - t0) / (t1 - t0);
+
+        Ok(volume_l / 1000.0 * density)
+    }
+
+    /// Subtracts one mass from another without going below zero
+    ///
+    /// Both quantities are normalized to kilograms first, so e.g. pounds can be
+    /// subtracted from tonnes. Useful for inventory where stock cannot be
+    /// negative.
+    ///
+    /// # Returns
+    ///
+    /// The difference clamped at zero, in the unit of `a`
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::CategoryMismatch`] if either quantity is not a mass
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use conversion_lib::mass::{self, MassUnit};
+    /// use conversion_lib::Quantity;
+    ///
+    /// let left = mass::subtract_saturating(
+    ///     Quantity::new(1.0, MassUnit::Tonnes),
+    ///     Quantity::new(250.0, MassUnit::Kilograms),
+    /// )
+    /// .unwrap();
+    /// assert_eq!(left, Quantity::new(0.75, MassUnit::Tonnes));
+    /// ```
+    pub fn subtract_saturating(a: Quantity, b: Quantity) -> Result<Quantity, Error> {
+        check_same_category(a.unit, Category::Mass.base_unit())?;
+        check_same_category(b.unit, Category::Mass.base_unit())?;
+
+        let remaining = (a.base_value() - b.base_value()).max(0.0);
+        Ok(Quantity {
+            value: a.unit.from_base(remaining),
+            unit: a.unit,
+        })
+    }
+
+    /// Calculates the weighted average of several masses
+    ///
+    /// Each mass is normalized to kilograms before weighting, so the items may
+    /// use different units.
+    ///
+    /// # Arguments
+    ///
+    /// * `items` - Pairs of mass and weight
+    /// * `out` - Unit of the returned average
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::CategoryMismatch`] if any quantity is not a mass and
+    /// [`Error::DivisionByZero`] if the weights sum to zero
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use conversion_lib::mass::{self, MassUnit};
+    /// use conversion_lib::Quantity;
+    ///
+    /// let average = mass::weighted_average(
+    ///     &[
+    ///         (Quantity::new(1.0, MassUnit::Tonnes), 1.0),
+    ///         (Quantity::new(500.0, MassUnit::Kilograms), 1.0),
+    ///     ],
+    ///     MassUnit::Kilograms,
+    /// )
+    /// .unwrap();
+    /// assert_eq!(average, Quantity::new(750.0, MassUnit::Kilograms));
+    /// ```
+    pub fn weighted_average(items: &[(Quantity, f64)], out: MassUnit) -> Result<Quantity, Error> {
+        let mut weighted_sum = 0.0;
+        let mut total_weight = 0.0;
+        for (quantity, weight) in items {
+            check_same_category(quantity.unit, Category::Mass.base_unit())?;
+            weighted_sum += quantity.base_value() * weight;
+            total_weight += weight;
+        }
+
+        if total_weight == 0.0 {
+            return Err(Error::DivisionByZero);
+        }
+
+        let unit = crate::Unit::from(out);
+        Ok(Quantity::new(
+            unit.from_base(weighted_sum / total_weight),
+            unit,
+        ))
+    }
+
+    /// Standard gravity on Earth in m/s²
+    pub const EARTH_GRAVITY: f64 = 9.80665;
+
+    /// Surface gravity on the Moon in m/s²
+    pub const MOON_GRAVITY: f64 = 1.62;
+
+    /// Surface gravity on Mars in m/s²
+    pub const MARS_GRAVITY: f64 = 3.71;
+
+    /// Calculates the weight of a mass at a given local gravity
+    ///
+    /// # Arguments
+    ///
+    /// * `mass_kg` - Mass in kilograms
+    /// * `gravity_mps2` - Local gravitational acceleration in m/s², e.g. [`EARTH_GRAVITY`]
+    ///
+    /// # Returns
+    ///
+    /// Weight (force) in newtons
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use conversion_lib::mass;
+    ///
+    /// let newtons = mass::weight(10.0, mass::EARTH_GRAVITY);
+    /// assert!((newtons - 98.0665).abs() < 1e-9);
+    /// ```
+    pub fn weight(mass_kg: f64, gravity_mps2: f64) -> f64 {
+        mass_kg * gravity_mps2
+    }
+}
+
+/// Area conversion functions
+///
+/// Factors are derived from the length factors with [`util::powered_factor`].
+pub mod area {
+    use crate::length::{self, LengthUnit};
+    use crate::util;
+
+    /// Converts square meters to square feet
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use conversion_lib::area;
+    ///
+    /// let square_feet = area::square_meters_to_square_feet(100.0);
+    /// assert!((square_feet - 1076.391).abs() < 0.001);
+    /// ```
+    pub fn square_meters_to_square_feet(square_meters: f64) -> f64 {
+        let feet_per_meter = length::convert(1.0, LengthUnit::Meters, LengthUnit::Feet);
+        square_meters * util::powered_factor(feet_per_meter, 2)
+    }
+
+    /// Converts square feet to square meters
+    pub fn square_feet_to_square_meters(square_feet: f64) -> f64 {
+        square_feet * util::powered_factor(LengthUnit::Feet.meters_per_unit(), 2)
+    }
+}
+
+/// Volume conversion functions
+pub mod volume {
+    use crate::length::{self, LengthUnit};
+    use crate::{check_same_category, util, Error, Quantity, Unit};
+    use std::str::FromStr;
+
+    /// Supported volume units
+    ///
+    /// US and imperial gallons differ by about 20%, so a bare `"gal"` is
+    /// deliberately not accepted when parsing.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    pub enum VolumeUnit {
+        CubicMeters,
+        Liters,
+        CubicFeet,
+        UsGallons,
+        ImperialGallons,
+    }
+
+    impl VolumeUnit {
+        /// Every supported volume unit
+        pub const ALL: [VolumeUnit; 5] = [
+            VolumeUnit::CubicMeters,
+            VolumeUnit::Liters,
+            VolumeUnit::CubicFeet,
+            VolumeUnit::UsGallons,
+            VolumeUnit::ImperialGallons,
+        ];
+
+        /// Returns the unit symbol, e.g. `"L"`
+        pub fn symbol(self) -> &'static str {
+            match self {
+                VolumeUnit::CubicMeters => "m³",
+                VolumeUnit::Liters => "L",
+                VolumeUnit::CubicFeet => "ft³",
+                VolumeUnit::UsGallons => "gal_us",
+                VolumeUnit::ImperialGallons => "gal_imp",
+            }
+        }
+
+        /// Returns alternative spellings accepted when parsing
+        pub fn aliases(self) -> &'static [&'static str] {
+            match self {
+                VolumeUnit::CubicMeters => &["m3", "cubic meter", "cubic meters"],
+                VolumeUnit::Liters => &["l", "liter", "liters", "litre", "litres"],
+                VolumeUnit::CubicFeet => &["ft3", "cubic foot", "cubic feet"],
+                VolumeUnit::UsGallons => &["usgal", "US gallon", "US gallons"],
+                VolumeUnit::ImperialGallons => &["impgal", "imperial gallon", "imperial gallons"],
+            }
+        }
+
+        /// Returns how many cubic meters one of this unit represents
+        pub fn cubic_meters_per_unit(self) -> f64 {
+            match self {
+                VolumeUnit::CubicMeters => 1.0,
+                VolumeUnit::Liters => 0.001,
+                VolumeUnit::CubicFeet => {
+                    util::powered_factor(LengthUnit::Feet.meters_per_unit(), 3)
+                }
+                VolumeUnit::UsGallons => 0.003785411784,
+                VolumeUnit::ImperialGallons => 0.00454609,
+            }
+        }
+    }
+
+    impl FromStr for VolumeUnit {
+        type Err = Error;
+
+        fn from_str(s: &str) -> Result<Self, Self::Err> {
+            let symbol = crate::normalize_unit_symbol(s);
+            let symbol = symbol.as_ref();
+            VolumeUnit::ALL
+                .into_iter()
+                .find(|unit| unit.symbol() == symbol || unit.aliases().contains(&symbol))
+                .ok_or_else(|| Error::UnknownUnit(s.to_string()))
+        }
+    }
+
+    /// The volume category as a [`CategoryConverter`](crate::CategoryConverter)
+    #[derive(Debug, Clone, Copy, Default)]
+    pub struct VolumeCategory;
+
+    impl crate::CategoryConverter for VolumeCategory {
+        fn category(&self) -> crate::Category {
+            crate::Category::Volume
+        }
+    }
+
+    /// Sums volumes given in any mix of units, expressed in `out`
+    ///
+    /// Every item is converted through liters with its own factor, so US and
+    /// imperial gallons can be mixed without silently treating one as the other.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::CategoryMismatch`] if any quantity is not a volume
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use conversion_lib::volume::{self, VolumeUnit};
+    /// use conversion_lib::Quantity;
+    ///
+    /// let tanks = [
+    ///     Quantity::new(1.0, VolumeUnit::UsGallons),
+    ///     Quantity::new(1.0, VolumeUnit::ImperialGallons),
+    /// ];
+    /// let total = volume::sum_checked(&tanks, VolumeUnit::Liters).unwrap();
+    /// assert!((total.value - 8.331).abs() < 0.001);
+    /// ```
+    pub fn sum_checked(quantities: &[Quantity], out: VolumeUnit) -> Result<Quantity, Error> {
+        let liters_per_out =
+            out.cubic_meters_per_unit() / VolumeUnit::Liters.cubic_meters_per_unit();
+        let mut liters = 0.0;
+        for quantity in quantities {
+            check_same_category(quantity.unit, Unit::Volume(out))?;
+            if let Unit::Volume(unit) = quantity.unit {
+                liters += quantity.value * unit.cubic_meters_per_unit()
+                    / VolumeUnit::Liters.cubic_meters_per_unit();
+            }
+        }
+        Ok(Quantity::new(liters / liters_per_out, out))
+    }
+
+    /// Reference temperature for normal cubic meters (Nm³): 0 °C
+    pub const NORMAL_TEMPERATURE_K: f64 = 273.15;
+
+    /// Reference temperature for standard cubic meters (Sm³): 15 °C
+    pub const STANDARD_TEMPERATURE_K: f64 = 288.15;
+
+    /// Converts cubic meters to cubic feet
+    ///
+    /// The factor is derived from the length factor with [`util::powered_factor`].
+    pub fn cubic_meters_to_cubic_feet(cubic_meters: f64) -> f64 {
+        let feet_per_meter = length::convert(1.0, LengthUnit::Meters, LengthUnit::Feet);
+        cubic_meters * util::powered_factor(feet_per_meter, 3)
+    }
+
+    /// Converts cubic feet to cubic meters
+    pub fn cubic_feet_to_cubic_meters(cubic_feet: f64) -> f64 {
+        cubic_feet * util::powered_factor(LengthUnit::Feet.meters_per_unit(), 3)
+    }
+
+    /// Converts normal cubic meters to standard cubic meters
+    ///
+    /// Normal conditions are 0 °C and standard conditions 15 °C, both at the
+    /// same pressure (101.325 kPa). By the ideal gas law the same amount of gas
+    /// occupies about 5.5% more volume at the warmer standard reference.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use conversion_lib::volume;
+    ///
+    /// let sm3 = volume::nm3_to_sm3(1000.0);
+    /// assert!((sm3 - 1054.91).abs() < 0.01);
+    /// ```
+    pub fn nm3_to_sm3(nm3: f64) -> f64 {
+        nm3 * STANDARD_TEMPERATURE_K / NORMAL_TEMPERATURE_K
+    }
+
+    /// Converts standard cubic meters (15 °C) to normal cubic meters (0 °C)
+    pub fn sm3_to_nm3(sm3: f64) -> f64 {
+        sm3 * NORMAL_TEMPERATURE_K / STANDARD_TEMPERATURE_K
+    }
+}
+
+/// Data size conversion functions
+///
+/// IEC units (KiB, MiB, GiB) are always powers of 1024. Whether KB, MB and GB
+/// mean powers of 1000 (SI) or of 1024 (the legacy JEDEC usage still shown by
+/// some operating systems) is chosen with [`ByteMode`](data::ByteMode).
+pub mod data {
+    /// Supported data size units
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub enum DataUnit {
+        Bytes,
+        Kilobytes,
+        Megabytes,
+        Gigabytes,
+        Kibibytes,
+        Mebibytes,
+        Gibibytes,
+    }
+
+    /// How decimal-named units such as KB are interpreted
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub enum ByteMode {
+        /// KB = 1024 bytes, MB = 1024² bytes, ...
+        Binary,
+        /// KB = 1000 bytes, MB = 1000² bytes, ...
+        Decimal,
+    }
+
+    impl DataUnit {
+        /// Returns the unit symbol, e.g. `"MiB"`
+        pub fn symbol(self) -> &'static str {
+            match self {
+                DataUnit::Bytes => "B",
+                DataUnit::Kilobytes => "KB",
+                DataUnit::Megabytes => "MB",
+                DataUnit::Gigabytes => "GB",
+                DataUnit::Kibibytes => "KiB",
+                DataUnit::Mebibytes => "MiB",
+                DataUnit::Gibibytes => "GiB",
+            }
+        }
+
+        /// Returns true for IEC units, which are always powers of 1024
+        pub fn is_binary(self) -> bool {
+            matches!(
+                self,
+                DataUnit::Kibibytes | DataUnit::Mebibytes | DataUnit::Gibibytes
+            )
+        }
+
+        /// Returns how many bytes one of this unit represents
+        pub fn bytes_per_unit(self, mode: ByteMode) -> f64 {
+            let power = match self {
+                DataUnit::Bytes => 0,
+                DataUnit::Kilobytes | DataUnit::Kibibytes => 1,
+                DataUnit::Megabytes | DataUnit::Mebibytes => 2,
+                DataUnit::Gigabytes | DataUnit::Gibibytes => 3,
+            };
+            let base: f64 = if self.is_binary() || mode == ByteMode::Binary {
+                1024.0
+            } else {
+                1000.0
+            };
+            base.powi(power)
+        }
+    }
+
+    /// Converts a data size between two units
+    ///
+    /// Binary and decimal units can be mixed freely; IEC units ignore `mode`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use conversion_lib::data::{self, ByteMode, DataUnit};
+    ///
+    /// let kb = data::convert(1.0, DataUnit::Kibibytes, DataUnit::Kilobytes, ByteMode::Decimal);
+    /// assert_eq!(kb, 1.024);
+    /// ```
+    pub fn convert(value: f64, from: DataUnit, to: DataUnit, mode: ByteMode) -> f64 {
+        value * from.bytes_per_unit(mode) / to.bytes_per_unit(mode)
+    }
+}
+
+/// Data rate conversion functions
+///
+/// Network rates are quoted in bits per second, storage throughput in bytes per
+/// second; one byte is 8 bits. Prefixes are decimal (1 kbps = 1000 bps).
+pub mod datarate {
+    /// Bits in one byte
+    pub const BITS_PER_BYTE: f64 = 8.0;
+
+    /// Converts megabits per second to megabytes per second
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use conversion_lib::datarate;
+    ///
+    /// assert_eq!(datarate::mbps_to_mbytes_per_s(100.0), 12.5);
+    /// ```
+    pub fn mbps_to_mbytes_per_s(mbps: f64) -> f64 {
+        mbps / BITS_PER_BYTE
+    }
+
+    /// Converts megabytes per second to megabits per second
+    pub fn mbytes_per_s_to_mbps(mbytes_per_s: f64) -> f64 {
+        mbytes_per_s * BITS_PER_BYTE
+    }
+
+    /// Converts bits per second to kilobits per second
+    pub fn bps_to_kbps(bps: f64) -> f64 {
+        bps / 1000.0
+    }
+
+    /// Converts kilobits per second to bits per second
+    pub fn kbps_to_bps(kbps: f64) -> f64 {
+        kbps * 1000.0
+    }
+
+    /// Converts kilobits per second to megabits per second
+    pub fn kbps_to_mbps(kbps: f64) -> f64 {
+        kbps / 1000.0
+    }
+
+    /// Converts megabits per second to kilobits per second
+    pub fn mbps_to_kbps(mbps: f64) -> f64 {
+        mbps * 1000.0
+    }
+
+    /// Converts kilobytes per second to kilobits per second
+    pub fn kbytes_per_s_to_kbps(kbytes_per_s: f64) -> f64 {
+        kbytes_per_s * BITS_PER_BYTE
+    }
+
+    /// Converts kilobits per second to kilobytes per second
+    pub fn kbps_to_kbytes_per_s(kbps: f64) -> f64 {
+        kbps / BITS_PER_BYTE
+    }
+}
+
+/// Flow calculation functions
+pub mod flow {
+    use crate::Error;
+
+    /// Estimates volumetric flow through an orifice from its pressure drop
+    ///
+    /// Uses the orifice equation Q = Cd·A·√(2·ΔP / ρ).
+    ///
+    /// # Arguments
+    ///
+    /// * `delta_p_pa` - Pressure drop across the orifice in Pascal
+    /// * `area_m2` - Orifice area in square meters
+    /// * `discharge_coeff` - Discharge coefficient (typically 0.6 – 0.65 for a sharp edge)
+    /// * `density_kgm3` - Fluid density in kg/m³
+    ///
+    /// # Returns
+    ///
+    /// Volumetric flow in m³/s
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidInput`] if the pressure drop, area or discharge
+    /// coefficient is negative, the density is not positive, or any input is
+    /// not finite
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use conversion_lib::flow;
+    ///
+    /// let q = flow::from_pressure_drop(2000.0, 0.01, 1.0, 1000.0).unwrap();
+    /// assert_eq!(q, 0.02);
+    /// ```
+    pub fn from_pressure_drop(
+        delta_p_pa: f64,
+        area_m2: f64,
+        discharge_coeff: f64,
+        density_kgm3: f64,
+    ) -> Result<f64, Error> {
+        for (name, value) in [
+            ("pressure drop", delta_p_pa),
+            ("area", area_m2),
+            ("discharge coefficient", discharge_coeff),
+        ] {
+            if !(value.is_finite() && value >= 0.0) {
+                return Err(Error::InvalidInput(format!(
+                    "{} must be finite and non-negative, got {}",
+                    name, value
+                )));
+            }
+        }
+        check_density(density_kgm3)?;
+        Ok(discharge_coeff * area_m2 * (2.0 * delta_p_pa / density_kgm3).sqrt())
+    }
+
+    fn check_density(density_kgm3: f64) -> Result<(), Error> {
+        if density_kgm3.is_finite() && density_kgm3 > 0.0 {
+            Ok(())
+        } else {
+            Err(Error::InvalidInput(format!(
+                "density must be finite and positive, got {}",
+                density_kgm3
+            )))
+        }
+    }
+
+    /// Converts a mass flow to a volumetric flow
+    ///
+    /// # Arguments
+    ///
+    /// * `kg_per_h` - Mass flow in kg/h
+    /// * `density_kgm3` - Fluid density in kg/m³
+    ///
+    /// # Returns
+    ///
+    /// Volumetric flow in m³/h
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidInput`] if `density_kgm3` is not finite and positive
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use conversion_lib::flow;
+    ///
+    /// assert_eq!(flow::mass_to_volumetric(1000.0, 1000.0), Ok(1.0));
+    /// ```
+    pub fn mass_to_volumetric(kg_per_h: f64, density_kgm3: f64) -> Result<f64, Error> {
+        check_density(density_kgm3)?;
+        Ok(kg_per_h / density_kgm3)
+    }
+
+    /// Converts a volumetric flow in m³/h to a mass flow in kg/h
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidInput`] if `density_kgm3` is not finite and positive
+    pub fn volumetric_to_mass(m3_per_h: f64, density_kgm3: f64) -> Result<f64, Error> {
+        check_density(density_kgm3)?;
+        Ok(m3_per_h * density_kgm3)
+    }
+}
+
+/// Energy conversion functions
+///
+/// # Calories
+///
+/// "Calorie" names two units a factor of 1000 apart. The small calorie (cal)
+/// is 4.184 J, while the food Calorie printed on nutrition labels is a
+/// kilocalorie (kcal), 4184 J. The calorie functions here say which one they
+/// mean in their names: [`small_cal_to_joules`](energy::small_cal_to_joules)
+/// for cal and [`kcal_to_joules`](energy::kcal_to_joules) for food Calories.
+/// Both use the thermochemical calorie.
+pub mod energy {
+    /// Joules in one kilowatt-hour
+    pub const JOULES_PER_KWH: f64 = 3.6e6;
+
+    /// Joules in one small (thermochemical) calorie
+    pub const JOULES_PER_SMALL_CALORIE: f64 = 4.184;
+
+    /// Joules in one kilocalorie, the food Calorie
+    pub const JOULES_PER_KCAL: f64 = 4184.0;
+
+    /// Fuels with typical lower heating values
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub enum Fuel {
+        Diesel,
+        Gasoline,
+        NaturalGas,
+        Coal,
+        Wood,
+    }
+
+    impl Fuel {
+        /// Returns the energy density in MJ/kg
+        pub fn energy_density_mj_per_kg(self) -> f64 {
+            match self {
+                Fuel::Diesel => 45.6,
+                Fuel::Gasoline => 46.4,
+                Fuel::NaturalGas => 47.1,
+                Fuel::Coal => 24.0,
+                Fuel::Wood => 16.0,
+            }
+        }
+    }
+
+    /// Converts kilowatt-hours to joules
+    pub fn kwh_to_joules(kwh: f64) -> f64 {
+        kwh * JOULES_PER_KWH
+    }
+
+    /// Converts small calories (cal, 4.184 J) to joules
+    ///
+    /// Not for nutrition labels: a food Calorie is 1000 small calories, use
+    /// [`kcal_to_joules`] for those.
+    pub fn small_cal_to_joules(cal: f64) -> f64 {
+        cal * JOULES_PER_SMALL_CALORIE
+    }
+
+    /// Converts kilocalories (kcal, the food Calorie) to joules
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use conversion_lib::energy;
+    ///
+    /// // A 250 Calorie snack
+    /// assert_eq!(energy::kcal_to_joules(250.0), 1_046_000.0);
+    /// ```
+    pub fn kcal_to_joules(kcal: f64) -> f64 {
+        kcal * JOULES_PER_KCAL
+    }
+
+    /// Converts joules to kilocalories (kcal, the food Calorie)
+    pub fn joules_to_kcal(joules: f64) -> f64 {
+        joules / JOULES_PER_KCAL
+    }
+
+    /// Calculates the mass of fuel holding a given amount of energy
+    ///
+    /// # Arguments
+    ///
+    /// * `joules` - Energy in joules
+    /// * `fuel` - Fuel whose energy density is used
+    ///
+    /// # Returns
+    ///
+    /// Equivalent fuel mass in kilograms
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use conversion_lib::energy::{self, Fuel};
+    ///
+    /// let kg = energy::to_fuel_equivalent(45.6e6, Fuel::Diesel);
+    /// assert!((kg - 1.0).abs() < 1e-12);
+    /// ```
+    pub fn to_fuel_equivalent(joules: f64, fuel: Fuel) -> f64 {
+        joules / (fuel.energy_density_mj_per_kg() * 1e6)
+    }
+}
+
+/// Fuel economy conversion functions
+///
+/// Miles per (US) gallon and liters per 100 km are reciprocal measures: their
+/// product is always [`MPG_TIMES_L_PER_100KM`](fuel::MPG_TIMES_L_PER_100KM). Both directions divide that
+/// one constant by the input, so neither is a numeric approximation of the
+/// other and round trips agree to within a couple of ULPs.
+pub mod fuel {
+    /// Product of miles per US gallon and liters per 100 km
+    ///
+    /// Equal to 100 km × 3.785411784 L/gal ÷ 1.609344 km/mi ≈ 235.215, built
+    /// from the exact definitions of the US gallon and the international mile.
+    pub const MPG_TIMES_L_PER_100KM: f64 = 100.0 * 3.785411784 / 1.609344;
+
+    /// Converts miles per US gallon to liters per 100 km
+    ///
+    /// Zero mpg (no distance covered) gives infinite consumption, and
+    /// infinite mpg gives zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use conversion_lib::fuel;
+    ///
+    /// let l_per_100km = fuel::mpg_to_l_per_100km(30.0);
+    /// assert!((l_per_100km - 7.8405).abs() < 1e-4);
+    /// ```
+    pub fn mpg_to_l_per_100km(mpg: f64) -> f64 {
+        MPG_TIMES_L_PER_100KM / mpg
+    }
+
+    /// Converts liters per 100 km to miles per US gallon
+    ///
+    /// The exact inverse of [`mpg_to_l_per_100km`]: zero consumption gives
+    /// infinite mpg, and infinite consumption gives zero.
+    pub fn l_per_100km_to_mpg(l_per_100km: f64) -> f64 {
+        MPG_TIMES_L_PER_100KM / l_per_100km
+    }
+}
+
+/// Electrical calculation functions
+pub mod electrical {
+    /// Temperature coefficient of a platinum RTD (IEC 60751) in 1/°C
+    pub const PLATINUM_ALPHA: f64 = 0.00385;
+
+    /// Calculates a conductor's resistance at a temperature
+    ///
+    /// Uses the linear model R = R_ref·(1 + α·(T − T_ref)).
+    ///
+    /// # Arguments
+    ///
+    /// * `r_ref` - Resistance at the reference temperature in ohms
+    /// * `alpha` - Temperature coefficient of resistance in 1/°C, e.g. [`PLATINUM_ALPHA`]
+    /// * `temp_c` - Temperature of interest in degrees Celsius
+    /// * `ref_temp_c` - Reference temperature in degrees Celsius
+    ///
+    /// # Returns
+    ///
+    /// Resistance in ohms
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use conversion_lib::electrical;
+    ///
+    /// let ohms = electrical::resistance_at_temp(100.0, electrical::PLATINUM_ALPHA, 100.0, 0.0);
+    /// assert!((ohms - 138.5).abs() < 1e-9);
+    /// ```
+    pub fn resistance_at_temp(r_ref: f64, alpha: f64, temp_c: f64, ref_temp_c: f64) -> f64 {
+        r_ref * (1.0 + alpha * (temp_c - ref_temp_c))
+    }
+}
+
+/// Angle conversion functions
+pub mod angle {
+    use crate::Error;
+
+    /// Converts degrees, minutes and seconds to decimal degrees
+    ///
+    /// The sign of `deg` applies to the whole angle, so `(-45, 30, 0.0)` is
+    /// -45.5°. Use [`parse_dms`] for angles between -1° and 0°.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `min` is 60 or more, or `sec` is outside 0 – 60
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use conversion_lib::angle;
+    ///
+    /// assert_eq!(angle::dms_to_degrees(45, 30, 0.0), 45.5);
+    /// ```
+    pub fn dms_to_degrees(deg: i32, min: u32, sec: f64) -> f64 {
+        assert!(min < 60, "minutes must be below 60");
+        assert!((0.0..60.0).contains(&sec), "seconds must be in 0 – 60");
+
+        let magnitude = f64::from(deg.unsigned_abs()) + f64::from(min) / 60.0 + sec / 3600.0;
+        if deg < 0 {
+            -magnitude
+        } else {
+            magnitude
+        }
+    }
+
+    /// Converts decimal degrees to degrees, minutes and seconds
+    ///
+    /// Minutes and seconds are always below 60. The sign is carried by the
+    /// degrees, so it is lost for angles between -1° and 0°; use
+    /// [`format_dms`] when that matters.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `deg` is not finite or its whole degrees do not fit in an `i32`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use conversion_lib::angle;
+    ///
+    /// assert_eq!(angle::degrees_to_dms(-45.5), (-45, 30, 0.0));
+    /// ```
+    pub fn degrees_to_dms(deg: f64) -> (i32, u32, f64) {
+        let (negative, degrees, minutes, seconds) = split(deg);
+        let degrees = degrees as i32;
+        (if negative { -degrees } else { degrees }, minutes, seconds)
+    }
+
+    /// Splits an angle into sign, whole degrees, whole minutes and seconds
+    fn split(deg: f64) -> (bool, u32, u32, f64) {
+        assert!(
+            deg.is_finite() && deg.abs() < f64::from(i32::MAX),
+            "angle must be finite and fit in i32 degrees"
+        );
+        let magnitude = deg.abs();
+        let mut degrees = magnitude.trunc() as u32;
+        let total_minutes = (magnitude - f64::from(degrees)) * 60.0;
+        let mut minutes = total_minutes.trunc() as u32;
+        let seconds = ((total_minutes - f64::from(minutes)) * 60.0).max(0.0);
+        if minutes == 60 {
+            minutes = 0;
+            degrees += 1;
+        }
+        (deg < 0.0, degrees, minutes, seconds)
+    }
+
+    /// Formats an angle as `D°M'S"` with the seconds rounded to `decimals` places
+    ///
+    /// Seconds that round up to 60 carry into the minutes and degrees.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `deg` is not finite or its whole degrees do not fit in an `i32`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use conversion_lib::angle;
+    ///
+    /// assert_eq!(angle::format_dms(45.504166666666666, 0), "45°30'15\"");
+    /// assert_eq!(angle::format_dms(-0.5, 1), "-0°30'00.0\"");
+    /// ```
+    pub fn format_dms(deg: f64, decimals: usize) -> String {
+        let (negative, mut degrees, mut minutes, seconds) = split(deg);
+
+        let scale = 10f64.powi(decimals as i32);
+        let mut seconds = (seconds * scale).round() / scale;
+        if seconds >= 60.0 {
+            seconds = 0.0;
+            minutes += 1;
+        }
+        if minutes >= 60 {
+            minutes = 0;
+            degrees += 1;
+        }
+
+        let width = if decimals == 0 { 2 } else { decimals + 3 };
+        format!(
+            "{}{}°{:02}'{:0width$.decimals$}\"",
+            if negative { "-" } else { "" },
+            degrees,
+            minutes,
+            seconds,
+            width = width,
+            decimals = decimals,
+        )
+    }
+
+    /// Parses an angle written as `D°M'S"`, e.g. `45°30'15"` or `-0° 30' 0"`
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Parse`] if a part is missing or malformed, or if the
+    /// minutes or seconds are 60 or more
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use conversion_lib::angle;
+    ///
+    /// assert_eq!(angle::parse_dms("45°30'00\"").unwrap(), 45.5);
+    /// ```
+    pub fn parse_dms(s: &str) -> Result<f64, Error> {
+        let invalid = || Error::Parse(format!("invalid DMS angle {:?}", s));
+
+        let trimmed = s.trim();
+        let (negative, rest) = match trimmed.strip_prefix('-') {
+            Some(rest) => (true, rest),
+            None => (false, trimmed),
+        };
+        let (degrees, rest) = rest.split_once('°').ok_or_else(invalid)?;
+        let (minutes, rest) = rest.split_once('\'').ok_or_else(invalid)?;
+        let seconds = rest.trim().strip_suffix('"').ok_or_else(invalid)?;
+
+        let degrees: u32 = degrees.trim().parse().map_err(|_| invalid())?;
+        let minutes: u32 = minutes.trim().parse().map_err(|_| invalid())?;
+        let seconds: f64 = seconds.trim().parse().map_err(|_| invalid())?;
+        if minutes >= 60 || !(0.0..60.0).contains(&seconds) {
+            return Err(invalid());
+        }
+
+        let magnitude = f64::from(degrees) + f64::from(minutes) / 60.0 + seconds / 3600.0;
+        Ok(if negative { -magnitude } else { magnitude })
+    }
+}
+
+/// Numeric helpers for working with converted data
+pub mod util {
+    use crate::{check_same_category, Error, Quantity};
+
+    /// Raises a linear conversion factor to a power for area or volume units
+    ///
+    /// Deriving ft² and ft³ factors from the ft factor keeps area and volume
+    /// conversions consistent with length instead of maintaining separate
+    /// rounded constants.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use conversion_lib::util;
+    ///
+    /// // 1 ft = 12 in, so 1 ft³ = 1728 in³
+    /// assert_eq!(util::powered_factor(12.0, 3), 1728.0);
+    /// ```
+    pub fn powered_factor(linear_factor: f64, power: u32) -> f64 {
+        (0..power).fold(1.0, |factor, _| factor * linear_factor)
+    }
+
+    /// Integrates evenly spaced samples using the trapezoidal rule
+    ///
+    /// Converting a power series in watts and integrating it gives energy in
+    /// joules; a series in kW sampled every hour integrates to kWh.
+    ///
+    /// # Arguments
+    ///
+    /// * `values` - Samples at a fixed interval
+    /// * `dt_seconds` - Time between consecutive samples in seconds
+    ///
+    /// # Panics
+    ///
+    /// Panics if fewer than two samples are given
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use conversion_lib::util;
+    ///
+    /// // Ramp from 0 W to 10 W over 10 seconds
+    /// let joules = util::trapezoidal_integrate(&[0.0, 5.0, 10.0], 5.0);
+    /// assert_eq!(joules, 50.0);
+    /// ```
+    pub fn trapezoidal_integrate(values: &[f64], dt_seconds: f64) -> f64 {
+        assert!(values.len() >= 2, "at least two samples are required");
+        values
+            .windows(2)
+            .map(|pair| (pair[0] + pair[1]) / 2.0 * dt_seconds)
+            .sum()
+    }
+
+    /// Largest number of bins [`histogram`] will allocate
+    pub const MAX_HISTOGRAM_BINS: usize = 1_000_000;
+
+    /// Counts values into fixed-width bins
+    ///
+    /// Bin `i` covers `[min + i·bin_width, min + (i+1)·bin_width)`. Enough bins
+    /// are returned to hold the largest value; values below `min`, NaNs and
+    /// infinities are ignored.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidInput`] if `bin_width` is not positive and finite,
+    /// or if a value would need more than [`MAX_HISTOGRAM_BINS`] bins
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use conversion_lib::util;
+    ///
+    /// let counts = util::histogram(&[0.5, 1.5, 1.7, 3.2], 1.0, 0.0).unwrap();
+    /// assert_eq!(counts, vec![1, 2, 0, 1]);
+    /// ```
+    pub fn histogram(values: &[f64], bin_width: f64, min: f64) -> Result<Vec<usize>, Error> {
+        if !(bin_width.is_finite() && bin_width > 0.0) {
+            return Err(Error::InvalidInput(format!(
+                "bin width must be positive and finite, got {}",
+                bin_width
+            )));
+        }
+
+        let mut counts = Vec::new();
+        for &value in values
+            .iter()
+            .filter(|&&value| value.is_finite() && value >= min)
+        {
+            let bin = ((value - min) / bin_width).floor();
+            if bin >= MAX_HISTOGRAM_BINS as f64 {
+                return Err(Error::InvalidInput(format!(
+                    "{} would need more than {} bins",
+                    value, MAX_HISTOGRAM_BINS
+                )));
+            }
+            let bin = bin as usize;
+            if bin >= counts.len() {
+                counts.resize(bin + 1, 0);
+            }
+            counts[bin] += 1;
+        }
+        Ok(counts)
+    }
+
+    /// Calculates the safety margin of an actual value against an allowable one
+    ///
+    /// The margin is `(allowable − actual) / allowable` with both quantities
+    /// normalized to the base unit of their category, so 0.2 means the actual
+    /// value is 20% below the allowable and a negative margin means it is
+    /// exceeded.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::CategoryMismatch`] for quantities of different categories
+    /// and [`Error::DivisionByZero`] when `allowable` is zero
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use conversion_lib::pressure::PressureUnit;
+    /// use conversion_lib::{util, Quantity};
+    ///
+    /// let margin = util::safety_margin(
+    ///     Quantity::new(8.0, PressureUnit::Bar),
+    ///     Quantity::new(1_000_000.0, PressureUnit::Pascal),
+    /// )
+    /// .unwrap();
+    /// assert!((margin - 0.2).abs() < 1e-12);
+    /// ```
+    pub fn safety_margin(actual: Quantity, allowable: Quantity) -> Result<f64, Error> {
+        check_same_category(actual.unit, allowable.unit)?;
+
+        let allowable_base = allowable.base_value();
+        if allowable_base == 0.0 {
+            return Err(Error::DivisionByZero);
+        }
+
+        Ok((allowable_base - actual.base_value()) / allowable_base)
+    }
+}
+
+/// Physical constants with their SI units
+pub mod constants {
+    /// Known constants as (name, value, SI unit)
+    pub const TABLE: [(&str, f64, &str); 8] = [
+        ("g", 9.80665, "m/s²"),
+        ("c", 299_792_458.0, "m/s"),
+        ("N_A", 6.022_140_76e23, "1/mol"),
+        ("R", 8.314_462_618, "J/(mol·K)"),
+        ("k_B", 1.380_649e-23, "J/K"),
+        ("h", 6.626_070_15e-34, "J·s"),
+        ("e", 1.602_176_634e-19, "C"),
+        ("atm", 101_325.0, "Pa"),
+    ];
+
+    /// Looks up a physical constant by its short name
+    ///
+    /// # Returns
+    ///
+    /// The value and its SI unit, or `None` for an unknown name
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use conversion_lib::constants;
+    ///
+    /// assert_eq!(constants::lookup("c"), Some((299_792_458.0, "m/s")));
+    /// ```
+    pub fn lookup(name: &str) -> Option<(f64, &'static str)> {
+        TABLE
+            .iter()
+            .find(|(known, _, _)| *known == name)
+            .map(|&(_, value, unit)| (value, unit))
+    }
+}
+
+/// The anaerobic digestion (AD) facility example used by the demo application
+pub mod demo {
+    use crate::{mass, percent_change, pressure, temperature, Error, Quantity, Region, Unit};
+
+    /// Key operating figures of an AD facility in metric units
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    pub struct FacilitySpec {
+        pub capacity_tonnes_per_day: f64,
+        pub operating_temp_c: f64,
+        pub operating_pressure_bar: f64,
+    }
+
+    impl FacilitySpec {
+        /// Renders the specification with imperial equivalents, one field per line
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// use conversion_lib::demo::FacilitySpec;
+        ///
+        /// let spec = FacilitySpec {
+        ///     capacity_tonnes_per_day: 500.0,
+        ///     operating_temp_c: 55.0,
+        ///     operating_pressure_bar: 1.5,
+        /// };
+        /// assert!(spec.report().contains("Operating Temperature: 55°C (131.00°F)"));
+        /// ```
+        pub fn report(&self) -> String {
+            let capacity = format!(
+                "  Capacity: {} tonnes/day ({:.2} tons/day)",
+                self.capacity_tonnes_per_day,
+                mass::tonnes_to_tons(self.capacity_tonnes_per_day)
+            );
+            let temperature = format!(
+                "  Operating Temperature: {}°C ({:.2}°F)",
+                self.operating_temp_c,
+                temperature::celsius_to_fahrenheit(self.operating_temp_c)
+            );
+            let pressure = format!(
+                "  Operating Pressure: {} bar ({:.2} PSI)",
+                self.operating_pressure_bar,
+                pressure::bar_to_psi(self.operating_pressure_bar)
+            );
+
+            format!(
+                "AD Facility Specifications:\n{}\n{}\n{}\n",
+                capacity, temperature, pressure
+            )
+        }
+    }
+
+    /// A field that differs between two specifications
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    pub struct FieldDiff {
+        /// Name of the [`FacilitySpec`] field
+        pub field: &'static str,
+        /// Old value in the metric unit of the specification
+        pub old: Quantity,
+        /// New value in the metric unit of the specification
+        pub new: Quantity,
+        /// Old value in the imperial equivalent unit
+        pub old_imperial: Quantity,
+        /// New value in the imperial equivalent unit
+        pub new_imperial: Quantity,
+        /// `new - old` in the metric unit
+        pub delta: f64,
+        /// Percentage change of the base values, `None` if the old base value is zero
+        ///
+        /// Temperatures are compared in kelvin, see [`percent_change`].
+        pub percent_change: Option<f64>,
+    }
+
+    /// Lists the fields that differ between two specifications
+    ///
+    /// Fields are reported in declaration order; equal fields are left out.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use conversion_lib::demo::{self, FacilitySpec};
+    ///
+    /// let before = FacilitySpec {
+    ///     capacity_tonnes_per_day: 500.0,
+    ///     operating_temp_c: 55.0,
+    ///     operating_pressure_bar: 1.5,
+    /// };
+    /// let after = FacilitySpec { capacity_tonnes_per_day: 550.0, ..before };
+    ///
+    /// let diffs = demo::diff_specs(&before, &after);
+    /// assert_eq!(diffs.len(), 1);
+    /// assert_eq!(diffs[0].field, "capacity_tonnes_per_day");
+    /// assert_eq!(diffs[0].percent_change, Some(10.0));
+    /// ```
+    pub fn diff_specs(a: &FacilitySpec, b: &FacilitySpec) -> Vec<FieldDiff> {
+        let fields: [(&str, f64, f64, Unit); 3] = [
+            (
+                "capacity_tonnes_per_day",
+                a.capacity_tonnes_per_day,
+                b.capacity_tonnes_per_day,
+                mass::MassUnit::Tonnes.into(),
+            ),
+            (
+                "operating_temp_c",
+                a.operating_temp_c,
+                b.operating_temp_c,
+                temperature::TemperatureUnit::Celsius.into(),
+            ),
+            (
+                "operating_pressure_bar",
+                a.operating_pressure_bar,
+                b.operating_pressure_bar,
+                pressure::PressureUnit::Bar.into(),
+            ),
+        ];
+
+        fields
+            .into_iter()
+            .filter(|&(_, old, new, _)| old != new)
+            .map(|(field, old, new, unit)| {
+                let (old, new) = (Quantity::new(old, unit), Quantity::new(new, unit));
+                let imperial = unit.in_region(Region::Imperial);
+                let to_imperial =
+                    |q: Quantity| Quantity::new(imperial.from_base(q.base_value()), imperial);
+                FieldDiff {
+                    field,
+                    old,
+                    new,
+                    old_imperial: to_imperial(old),
+                    new_imperial: to_imperial(new),
+                    delta: new.value - old.value,
+                    percent_change: percent_change(old, new).ok(),
+                }
+            })
+            .collect()
+    }
+
+    /// Parses a report produced by [`FacilitySpec::report`] back into a specification
+    ///
+    /// Only the metric values are read; the imperial equivalents in brackets
+    /// are ignored.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Parse`] if a field is missing or its value is not a number
+    pub fn parse_report(s: &str) -> Result<FacilitySpec, Error> {
+        Ok(FacilitySpec {
+            capacity_tonnes_per_day: parse_field(s, "Capacity:", "tonnes/day")?,
+            operating_temp_c: parse_field(s, "Operating Temperature:", "°C")?,
+            operating_pressure_bar: parse_field(s, "Operating Pressure:", "bar")?,
+        })
+    }
+
+    /// Reads the number between `label` and `unit` on the line starting with `label`
+    fn parse_field(report: &str, label: &str, unit: &str) -> Result<f64, Error> {
+        let line = report
+            .lines()
+            .map(str::trim)
+            .find(|line| line.starts_with(label))
+            .ok_or_else(|| Error::Parse(format!("missing field {:?}", label)))?;
+
+        let value = line[label.len()..]
+            .trim_start()
+            .split(unit)
+            .next()
+            .unwrap_or_default()
+            .trim();
+        value
+            .parse()
+            .map_err(|_| Error::Parse(format!("invalid number {:?} for {:?}", value, label)))
+    }
+}
+
+/// Products and quotients of coherent SI units
+pub mod compound {
+    use crate::Error;
+    use std::fmt;
+    use std::str::FromStr;
+
+    /// Known symbols with their SI dimension, see [`crate::dimension`]
+    const SYMBOLS: [(&str, [i8; 7]); 11] = [
+        ("m", [1, 0, 0, 0, 0, 0, 0]),
+        ("kg", [0, 1, 0, 0, 0, 0, 0]),
+        ("s", [0, 0, 1, 0, 0, 0, 0]),
+        ("A", [0, 0, 0, 1, 0, 0, 0]),
+        ("K", [0, 0, 0, 0, 1, 0, 0]),
+        ("mol", [0, 0, 0, 0, 0, 1, 0]),
+        ("cd", [0, 0, 0, 0, 0, 0, 1]),
+        ("N", [1, 1, -2, 0, 0, 0, 0]),
+        ("Pa", [-1, 1, -2, 0, 0, 0, 0]),
+        ("J", [2, 1, -2, 0, 0, 0, 0]),
+        ("W", [2, 1, -3, 0, 0, 0, 0]),
+    ];
+
+    const SUPERSCRIPTS: [char; 10] = ['⁰', '¹', '²', '³', '⁴', '⁵', '⁶', '⁷', '⁸', '⁹'];
+
+    /// A unit built from known symbols raised to integer powers, e.g. N·m⁻²
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub struct CompoundUnit {
+        factors: Vec<(&'static str, i8)>,
+    }
+
+    impl CompoundUnit {
+        /// Creates a compound unit from symbol and exponent pairs
+        ///
+        /// # Errors
+        ///
+        /// Returns [`Error::UnknownUnit`] if a symbol is not a coherent SI unit
+        /// and [`Error::Parse`] if the combined exponents overflow an `i8`
+        pub fn new(factors: &[(&str, i8)]) -> Result<Self, Error> {
+            let factors = factors
+                .iter()
+                .map(|&(symbol, exponent)| Ok((lookup(symbol)?.0, exponent)))
+                .collect::<Result<_, Error>>()?;
+            CompoundUnit::checked(factors)
+        }
+
+        /// Rejects factors whose merged exponents or dimension overflow, so that
+        /// [`dimension`](CompoundUnit::dimension) and
+        /// [`simplify`](CompoundUnit::simplify) cannot
+        fn checked(factors: Vec<(&'static str, i8)>) -> Result<Self, Error> {
+            let overflow = || Error::Parse("unit exponent out of range".to_string());
+            let mut merged: Vec<(&'static str, i8)> = Vec::new();
+            let mut total = [0i8; 7];
+            for &(symbol, exponent) in &factors {
+                match merged.iter_mut().find(|(s, _)| *s == symbol) {
+                    Some((_, sum)) => *sum = sum.checked_add(exponent).ok_or_else(overflow)?,
+                    None => merged.push((symbol, exponent)),
+                }
+                for (sum, base) in total.iter_mut().zip(lookup(symbol)?.1) {
+                    *sum = base
+                        .checked_mul(exponent)
+                        .and_then(|product| sum.checked_add(product))
+                        .ok_or_else(overflow)?;
+                }
+            }
+            Ok(CompoundUnit { factors })
+        }
+
+        /// Returns the combined SI dimension of all factors
+        pub fn dimension(&self) -> [i8; 7] {
+            let mut total = [0; 7];
+            for &(symbol, exponent) in &self.factors {
+                let (_, dimension) = SYMBOLS.iter().find(|(s, _)| *s == symbol).unwrap();
+                for (sum, base) in total.iter_mut().zip(dimension) {
+                    *sum += base * exponent;
+                }
+            }
+            total
+        }
+
+        /// Reduces the unit to a single named unit when one matches its dimension
+        ///
+        /// Otherwise repeated symbols are merged and cancelled factors removed.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// use conversion_lib::compound::CompoundUnit;
+        ///
+        /// let unit: CompoundUnit = "N/m²".parse().unwrap();
+        /// assert_eq!(unit.simplify().to_string(), "Pa");
+        /// ```
+        pub fn simplify(&self) -> CompoundUnit {
+            let dimension = self.dimension();
+            if let Some(&(symbol, _)) = SYMBOLS.iter().find(|(_, d)| *d == dimension) {
+                return CompoundUnit {
+                    factors: vec![(symbol, 1)],
+                };
+            }
+
+            let mut factors: Vec<(&'static str, i8)> = Vec::new();
+            for &(symbol, exponent) in &self.factors {
+                match factors.iter_mut().find(|(s, _)| *s == symbol) {
+                    Some((_, sum)) => *sum += exponent,
+                    None => factors.push((symbol, exponent)),
+                }
+            }
+            factors.retain(|&(_, exponent)| exponent != 0);
+            CompoundUnit { factors }
+        }
+    }
+
+    fn lookup(symbol: &str) -> Result<&'static (&'static str, [i8; 7]), Error> {
+        SYMBOLS
+            .iter()
+            .find(|(s, _)| *s == symbol)
+            .ok_or_else(|| Error::UnknownUnit(symbol.to_string()))
+    }
+
+    /// Splits a factor such as `m²`, `s^-1` or `kg` into symbol and exponent
+    fn parse_factor(factor: &str) -> Result<(&'static str, i8), Error> {
+        let invalid = || Error::Parse(format!("invalid exponent in {:?}", factor));
+
+        if let Some((symbol, exponent)) = factor.split_once('^') {
+            let exponent = exponent.parse().map_err(|_| invalid())?;
+            return Ok((lookup(symbol)?.0, exponent));
+        }
+
+        let split = factor
+            .find(|c| c == '⁻' || SUPERSCRIPTS.contains(&c))
+            .unwrap_or(factor.len());
+        let (symbol, superscript) = factor.split_at(split);
+        let exponent = if superscript.is_empty() {
+            1
+        } else {
+            let (sign, digits) = match superscript.strip_prefix('⁻') {
+                Some(digits) => (-1, digits),
+                None => (1, superscript),
+            };
+            let mut value: i8 = 0;
+            for c in digits.chars() {
+                let digit = SUPERSCRIPTS
+                    .iter()
+                    .position(|&s| s == c)
+                    .ok_or_else(invalid)?;
+                value = value
+                    .checked_mul(10)
+                    .and_then(|v| v.checked_add(digit as i8))
+                    .ok_or_else(invalid)?;
+            }
+            sign * value
+        };
+        Ok((lookup(symbol)?.0, exponent))
+    }
+
+    fn superscript(magnitude: u8) -> String {
+        magnitude
+            .to_string()
+            .chars()
+            .map(|c| SUPERSCRIPTS[c.to_digit(10).unwrap() as usize])
+            .collect()
+    }
+
+    /// Parses units such as `N/m²`, `J/s`, `N·m` or `kg*m^2/s^2`
+    impl FromStr for CompoundUnit {
+        type Err = Error;
+
+        fn from_str(s: &str) -> Result<Self, Self::Err> {
+            let mut factors = Vec::new();
+            for (index, part) in s.split('/').enumerate() {
+                let sign = if index == 0 { 1 } else { -1 };
+                for factor in part.split(['·', '*']).map(str::trim) {
+                    if index == 0 && factor == "1" {
+                        continue;
+                    }
+                    let (symbol, exponent) = parse_factor(factor)?;
+                    let exponent = exponent
+                        .checked_mul(sign)
+                        .ok_or_else(|| Error::Parse(format!("invalid exponent in {:?}", factor)))?;
+                    factors.push((symbol, exponent));
+                }
+            }
+            CompoundUnit::checked(factors)
+        }
+    }
+
+    impl fmt::Display for CompoundUnit {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            let format_part = |negative: bool| {
+                self.factors
+                    .iter()
+                    .filter(|&&(_, exponent)| (exponent < 0) == negative)
+                    .map(|&(symbol, exponent)| match exponent.unsigned_abs() {
+                        1 => symbol.to_string(),
+                        magnitude => format!("{}{}", symbol, superscript(magnitude)),
+                    })
+                    .collect::<Vec<_>>()
+                    .join("·")
+            };
+
+            let (numerator, denominator) = (format_part(false), format_part(true));
+            // A unit whose factors all cancel, such as m/m, is dimensionless
+            match (numerator.is_empty(), denominator.is_empty()) {
+                (true, true) => write!(f, "1"),
+                (false, true) => write!(f, "{}", numerator),
+                (true, false) => write!(f, "1/{}", denominator),
+                (false, false) => write!(f, "{}/{}", numerator, denominator),
+            }
+        }
+    }
+}
+
+/// Compile-time checked quantities
+///
+/// Each unit is a marker type implementing [`Dimension`](typed::Dimension),
+/// and [`Quantity<U>`](typed::Quantity) carries its unit in the type.
+/// [`convert`](typed::convert) only
+/// accepts a target unit of the same physical kind, so mixing up length and
+/// mass is a compile error rather than a runtime
+/// [`Error::CategoryMismatch`].
+///
+/// ```compile_fail
+/// use conversion_lib::typed::{self, Kilograms, Meters, Quantity};
+///
+/// let mass: Quantity<Kilograms> = typed::convert(Quantity::<Meters>::new(1.0));
+/// ```
+pub mod typed {
+    use crate::{length, mass, pressure, temperature, volume, Unit};
+    use std::fmt;
+    use std::marker::PhantomData;
+
+    /// Physical kind of length units
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub struct Length;
+    /// Physical kind of temperature units
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub struct Temperature;
+    /// Physical kind of pressure units
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub struct Pressure;
+    /// Physical kind of mass units
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub struct Mass;
+    /// Physical kind of volume units
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub struct Volume;
+
+    /// A unit known at compile time
+    pub trait Dimension {
+        /// Physical kind shared by all units that convert into each other
+        type Kind;
+        /// The runtime unit this marker stands for
+        const UNIT: Unit;
+    }
+
+    macro_rules! typed_units {
+        ($($name:ident: $kind:ident = $unit:expr;)*) => {
+            $(
+                #[doc = concat!("Marker for [`", stringify!($unit), "`]")]
+                #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+                pub struct $name;
+
+                impl Dimension for $name {
+                    type Kind = $kind;
+                    const UNIT: Unit = Unit::$kind($unit);
+                }
+            )*
+        };
+    }
+
+    typed_units! {
+        Micrometers: Length = length::LengthUnit::Micrometers;
+        Millimeters: Length = length::LengthUnit::Millimeters;
+        Centimeters: Length = length::LengthUnit::Centimeters;
+        Meters: Length = length::LengthUnit::Meters;
+        Kilometers: Length = length::LengthUnit::Kilometers;
+        Inches: Length = length::LengthUnit::Inches;
+        Feet: Length = length::LengthUnit::Feet;
+        Yards: Length = length::LengthUnit::Yards;
+        Miles: Length = length::LengthUnit::Miles;
+        Celsius: Temperature = temperature::TemperatureUnit::Celsius;
+        Fahrenheit: Temperature = temperature::TemperatureUnit::Fahrenheit;
+        Kelvin: Temperature = temperature::TemperatureUnit::Kelvin;
+        Pascal: Pressure = pressure::PressureUnit::Pascal;
+        Bar: Pressure = pressure::PressureUnit::Bar;
+        Psi: Pressure = pressure::PressureUnit::Psi;
+        Grams: Mass = mass::MassUnit::Grams;
+        Kilograms: Mass = mass::MassUnit::Kilograms;
+        Pounds: Mass = mass::MassUnit::Pounds;
+        Tonnes: Mass = mass::MassUnit::Tonnes;
+        CubicMeters: Volume = volume::VolumeUnit::CubicMeters;
+        Liters: Volume = volume::VolumeUnit::Liters;
+    }
+
+    /// A value whose unit is part of its type
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    pub struct Quantity<U> {
+        value: f64,
+        unit: PhantomData<U>,
+    }
+
+    impl<U: Dimension> Quantity<U> {
+        /// Creates a quantity in unit `U`
+        pub fn new(value: f64) -> Self {
+            Quantity {
+                value,
+                unit: PhantomData,
+            }
+        }
+
+        /// Returns the value in unit `U`
+        pub fn value(&self) -> f64 {
+            self.value
+        }
+
+        /// Converts to the untyped [`crate::Quantity`]
+        pub fn erase(&self) -> crate::Quantity {
+            crate::Quantity::new(self.value, U::UNIT)
+        }
+    }
+
+    impl<U: Dimension> fmt::Display for Quantity<U> {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "{} {}", self.value, U::UNIT)
+        }
+    }
+
+    /// Converts a quantity to another unit of the same kind
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use conversion_lib::typed::{self, Feet, Meters, Quantity};
+    ///
+    /// let feet: Quantity<Feet> = typed::convert(Quantity::<Meters>::new(0.3048));
+    /// assert!((feet.value() - 1.0).abs() < 1e-12);
+    /// ```
+    pub fn convert<F: Dimension, T: Dimension<Kind = F::Kind>>(q: Quantity<F>) -> Quantity<T> {
+        if F::UNIT == T::UNIT {
+            return Quantity::new(q.value);
+        }
+        Quantity::new(T::UNIT.from_base(F::UNIT.to_base(q.value)))
+    }
+}
+
+/// Geographic distance conversions on a spherical Earth
+///
+/// The Earth is treated as a sphere whose meridians have the mean length of
+/// the real ellipsoid's, so results are within about 1% of geodesic values.
+/// Use a proper geodesy library for survey-grade work.
+pub mod geo {
+    /// Mean length of a meridian from pole to pole in meters
+    pub const MERIDIAN_LENGTH_M: f64 = 20_003_930.0;
+
+    /// Distance covered by one degree of latitude in meters
+    pub const METERS_PER_DEGREE_LAT: f64 = MERIDIAN_LENGTH_M / 180.0;
+
+    /// Converts degrees of latitude to meters along a meridian
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use conversion_lib::geo;
+    ///
+    /// assert!((geo::lat_degrees_to_meters(1.0) - 111_133.0).abs() < 1.0);
+    /// ```
+    pub fn lat_degrees_to_meters(degrees: f64) -> f64 {
+        degrees * METERS_PER_DEGREE_LAT
+    }
+
+    /// Converts meters along a meridian to degrees of latitude
+    pub fn meters_to_lat_degrees(meters: f64) -> f64 {
+        meters / METERS_PER_DEGREE_LAT
+    }
+
+    /// Converts degrees of longitude to meters along a parallel
+    ///
+    /// A parallel shrinks by cos(latitude), so a degree of longitude is as
+    /// long as a degree of latitude at the equator and zero at the poles.
+    ///
+    /// # Arguments
+    ///
+    /// * `degrees` - Longitude difference in degrees
+    /// * `latitude_deg` - Latitude of the parallel in degrees
+    ///
+    /// # Returns
+    ///
+    /// Distance in meters
+    pub fn lon_degrees_to_meters(degrees: f64, latitude_deg: f64) -> f64 {
+        degrees * METERS_PER_DEGREE_LAT * latitude_deg.to_radians().cos()
+    }
+
+    /// Converts meters along a parallel to degrees of longitude
+    ///
+    /// The result grows without bound toward the poles, where a parallel has
+    /// no length.
+    pub fn meters_to_lon_degrees(meters: f64, latitude_deg: f64) -> f64 {
+        meters / (METERS_PER_DEGREE_LAT * latitude_deg.to_radians().cos())
+    }
+}
+
+/// Period, frequency and rotational speed conversions
+///
+/// These quantities are reciprocals of each other, so the conversions are
+/// [`ConversionKind::Reciprocal`] and reject zero input.
+pub mod time {
+    use crate::{ConversionKind, Error};
+
+    /// Period in seconds to frequency in hertz, and back
+    pub const PERIOD_FREQUENCY: ConversionKind = ConversionKind::Reciprocal { constant: 1.0 };
+
+    /// Rotational speed in RPM to seconds per revolution, and back
+    pub const RPM_PERIOD: ConversionKind = ConversionKind::Reciprocal { constant: 60.0 };
+
+    /// Converts a period in seconds to a frequency in hertz
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::DivisionByZero`] for a zero period
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use conversion_lib::time;
+    ///
+    /// assert_eq!(time::period_to_frequency(2.0).unwrap(), 0.5);
+    /// ```
+    pub fn period_to_frequency(seconds: f64) -> Result<f64, Error> {
+        PERIOD_FREQUENCY.apply(seconds)
+    }
+
+    /// Converts a frequency in hertz to a period in seconds
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::DivisionByZero`] for a zero frequency
+    pub fn frequency_to_period(hz: f64) -> Result<f64, Error> {
+        PERIOD_FREQUENCY.apply(hz)
+    }
+
+    /// Converts a rotational speed in RPM to the time of one revolution in seconds
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::DivisionByZero`] for zero RPM
+    pub fn rpm_to_period(rpm: f64) -> Result<f64, Error> {
+        RPM_PERIOD.apply(rpm)
+    }
+
+    /// Converts the time of one revolution in seconds to a rotational speed in RPM
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::DivisionByZero`] for a zero period
+    pub fn period_to_rpm(seconds: f64) -> Result<f64, Error> {
+        RPM_PERIOD.apply(seconds)
+    }
+}
+
+/// Density calculations
+pub mod density {
+    use crate::Error;
+
+    /// How far the volume fractions of a mixture may sum away from 1
+    pub const FRACTION_SUM_TOLERANCE: f64 = 1e-6;
+
+    /// Calculates the density of a mixture from its components
+    ///
+    /// The blend is volume-weighted, ρ = Σ ρᵢ·φᵢ, which assumes the volumes
+    /// are additive (no contraction on mixing).
+    ///
+    /// # Arguments
+    ///
+    /// * `components` - (density in kg/m³, volume fraction) for each component
+    ///
+    /// # Returns
+    ///
+    /// Density of the mixture in kg/m³
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidInput`] if there are no components, a fraction
+    /// is outside 0 to 1, or the fractions do not sum to 1 within
+    /// [`FRACTION_SUM_TOLERANCE`]
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use conversion_lib::density;
+    ///
+    /// let blend = density::mixture_density(&[(1000.0, 0.5), (800.0, 0.5)]).unwrap();
+    /// assert!((blend - 900.0).abs() < 1e-9);
+    /// ```
+    pub fn mixture_density(components: &[(f64, f64)]) -> Result<f64, Error> {
+        if components.is_empty() {
+            return Err(Error::InvalidInput(
+                "a mixture needs at least one component".to_string(),
+            ));
+        }
+        if let Some(&(_, fraction)) = components
+            .iter()
+            .find(|(_, fraction)| !(0.0..=1.0).contains(fraction))
+        {
+            return Err(Error::InvalidInput(format!(
+                "volume fraction {} must be between 0 and 1",
+                fraction
+            )));
+        }
+
+        let total: f64 = components.iter().map(|&(_, fraction)| fraction).sum();
+        if (total - 1.0).abs() > FRACTION_SUM_TOLERANCE {
+            return Err(Error::InvalidInput(format!(
+                "volume fractions sum to {}, expected 1",
+                total
+            )));
+        }
+
+        Ok(components
+            .iter()
+            .map(|&(density, fraction)| density * fraction)
+            .sum())
+    }
+}
+
+/// Languages available for unit display names
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Lang {
+    En,
+    De,
+    Fr,
+}
+
+/// Measurement system preferred for output
+///
+/// This is the same enum that [`system`] classifies units with, so a unit's
+/// own system can be passed straight back as a target.
+pub type Region = MeasurementSystem;
+
+/// Application domain used to pick conventional output units
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Context {
+    /// SI units for general engineering work
+    General,
+    /// US plumbing: psi, inches, °F and US gallons
+    Plumbing,
+    /// Aviation: altitude in feet, fuel in US gallons and pounds, °C
+    Aviation,
+}
+
+/// Preferred output unit for each category
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Preferences {
+    pub length: length::LengthUnit,
+    pub temperature: temperature::TemperatureUnit,
+    pub pressure: pressure::PressureUnit,
+    pub mass: mass::MassUnit,
+    pub volume: volume::VolumeUnit,
+}
+
+impl Preferences {
+    /// Returns the preferred unit for a category
+    pub fn unit_for(&self, category: Category) -> Unit {
+        match category {
+            Category::Length => self.length.into(),
+            Category::Temperature => self.temperature.into(),
+            Category::Pressure => self.pressure.into(),
+            Category::Mass => self.mass.into(),
+            Category::Volume => self.volume.into(),
+        }
+    }
+}
+
+/// Returns the conventional units of a domain, one per category
+///
+/// # Examples
+///
+/// ```
+/// use conversion_lib::pressure::PressureUnit;
+/// use conversion_lib::{preferred_units, Category, Context};
+///
+/// let plumbing = preferred_units(Context::Plumbing);
+/// assert_eq!(plumbing.pressure, PressureUnit::Psi);
+/// assert_eq!(plumbing.unit_for(Category::Length).symbol(), "in");
+/// ```
+pub fn preferred_units(context: Context) -> Preferences {
+    use length::LengthUnit as L;
+    use mass::MassUnit as M;
+    use pressure::PressureUnit as P;
+    use temperature::TemperatureUnit as T;
+    use volume::VolumeUnit as V;
+
+    match context {
+        Context::General => Preferences {
+            length: L::Meters,
+            temperature: T::Celsius,
+            pressure: P::Bar,
+            mass: M::Kilograms,
+            volume: V::Liters,
+        },
+        Context::Plumbing => Preferences {
+            length: L::Inches,
+            temperature: T::Fahrenheit,
+            pressure: P::Psi,
+            mass: M::Pounds,
+            volume: V::UsGallons,
+        },
+        Context::Aviation => Preferences {
+            length: L::Feet,
+            temperature: T::Celsius,
+            pressure: P::Psi,
+            mass: M::Pounds,
+            volume: V::UsGallons,
+        },
+    }
+}
+
+/// Measurement system a unit belongs to, or that output should use
+///
+/// See [`Unit::in_region`] for how units are mapped into each system.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MeasurementSystem {
+    Metric,
+    Imperial,
+    /// US customary units that differ from imperial, such as the short ton
+    US,
+    /// Historical scales outside the modern systems, such as Delisle
+    Other,
+}
+
+/// Classifies a unit by its measurement system
+///
+/// # Examples
+///
+/// ```
+/// use conversion_lib::length::LengthUnit;
+/// use conversion_lib::{system, MeasurementSystem};
+///
+/// assert_eq!(system(LengthUnit::Feet), MeasurementSystem::Imperial);
+/// ```
+pub fn system(unit: impl Into<Unit>) -> MeasurementSystem {
+    use length::LengthUnit as L;
+    use mass::MassUnit as M;
+    use pressure::PressureUnit as P;
+    use temperature::TemperatureUnit as T;
+    use volume::VolumeUnit as V;
+
+    match unit.into() {
+        Unit::Length(
+            L::Micrometers | L::Millimeters | L::Centimeters | L::Meters | L::Kilometers,
+        )
+        | Unit::Temperature(T::Celsius | T::Kelvin)
+        | Unit::Pressure(P::Pascal | P::Bar)
+        | Unit::Mass(M::Grams | M::Kilograms | M::Tonnes)
+        | Unit::Volume(V::CubicMeters | V::Liters) => MeasurementSystem::Metric,
+        Unit::Length(L::Inches | L::Feet | L::Yards | L::Miles)
+        | Unit::Temperature(T::Fahrenheit)
+        | Unit::Pressure(P::Psi)
+        | Unit::Mass(M::Pounds)
+        | Unit::Volume(V::CubicFeet | V::ImperialGallons) => MeasurementSystem::Imperial,
+        Unit::Length(L::UsSurveyFeet) | Unit::Mass(M::Tons) | Unit::Volume(V::UsGallons) => {
+            MeasurementSystem::US
+        }
+        Unit::Temperature(T::Delisle) => MeasurementSystem::Other,
+    }
+}
+
+/// Kind of measurement scale a unit is defined on
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ScaleType {
+    /// Zero means none of the quantity, so ratios are meaningful (meters, kelvin)
+    Ratio,
+    /// Zero is arbitrary, so only differences are meaningful (degrees Celsius)
+    Interval,
+}
+
+/// Returns the scale type of a unit
+///
+/// Kelvin is an absolute scale and therefore a ratio scale, while Celsius,
+/// Fahrenheit and Delisle are interval scales.
+pub fn scale_type(unit: impl Into<Unit>) -> ScaleType {
+    use temperature::TemperatureUnit as T;
+
+    match unit.into() {
+        Unit::Temperature(T::Celsius | T::Fahrenheit | T::Delisle) => ScaleType::Interval,
+        _ => ScaleType::Ratio,
+    }
+}
+
+/// Measurement categories covered by the library
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Category {
+    Length,
+    Temperature,
+    Pressure,
+    Mass,
+    Volume,
+}
+
+impl Category {
+    /// Every category handled by [`Unit`]
+    pub const ALL: [Category; 5] = [
+        Category::Length,
+        Category::Temperature,
+        Category::Pressure,
+        Category::Mass,
+        Category::Volume,
+    ];
+
+    /// Returns the base unit that every unit of this category converts through
+    pub fn base_unit(self) -> Unit {
+        match self {
+            Category::Length => Unit::Length(length::LengthUnit::Meters),
+            Category::Temperature => Unit::Temperature(temperature::TemperatureUnit::Kelvin),
+            Category::Pressure => Unit::Pressure(pressure::PressureUnit::Pascal),
+            Category::Mass => Unit::Mass(mass::MassUnit::Kilograms),
+            Category::Volume => Unit::Volume(volume::VolumeUnit::CubicMeters),
+        }
+    }
+
+    /// Returns the typical range of values for this category in its base unit
+    ///
+    /// These are documented defaults for flagging suspicious data, not physical
+    /// limits:
+    ///
+    /// * Length: 0 – 40 000 km (up to the Earth's circumference)
+    /// * Temperature: 173.15 – 473.15 K (−100 °C to 200 °C)
+    /// * Pressure: 0 – 100 MPa (1000 bar)
+    /// * Mass: 0 – 1 000 000 kg (1000 tonnes)
+    /// * Volume: 0 – 1 000 000 m³ (a large storage reservoir)
+    pub fn typical_range(self) -> (f64, f64) {
+        match self {
+            Category::Length => (0.0, 40_000_000.0),
+            Category::Temperature => (173.15, 473.15),
+            Category::Pressure => (0.0, 100_000_000.0),
+            Category::Mass => (0.0, 1_000_000.0),
+            Category::Volume => (0.0, 1_000_000.0),
+        }
+    }
+}
+
+/// A unit from any supported category
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Unit {
+    Length(length::LengthUnit),
+    Temperature(temperature::TemperatureUnit),
+    Pressure(pressure::PressureUnit),
+    Mass(mass::MassUnit),
+    Volume(volume::VolumeUnit),
+}
+
+impl Unit {
+    /// Returns every supported unit across all categories
+    pub fn all() -> Vec<Unit> {
+        let lengths = length::LengthUnit::ALL.into_iter().map(Unit::from);
+        let temperatures = temperature::TemperatureUnit::ALL
+            .into_iter()
+            .map(Unit::from);
+        let pressures = pressure::PressureUnit::ALL.into_iter().map(Unit::from);
+        let masses = mass::MassUnit::ALL.into_iter().map(Unit::from);
+        let volumes = volume::VolumeUnit::ALL.into_iter().map(Unit::from);
+        lengths
+            .chain(temperatures)
+            .chain(pressures)
+            .chain(masses)
+            .chain(volumes)
+            .collect()
+    }
+
+    /// Returns the category this unit belongs to
+    pub fn category(self) -> Category {
+        match self {
+            Unit::Length(_) => Category::Length,
+            Unit::Temperature(_) => Category::Temperature,
+            Unit::Pressure(_) => Category::Pressure,
+            Unit::Mass(_) => Category::Mass,
+            Unit::Volume(_) => Category::Volume,
+        }
+    }
+
+    /// Returns the unit symbol, e.g. `"m"` or `"°C"`
+    pub fn symbol(self) -> &'static str {
+        match self {
+            Unit::Length(unit) => unit.symbol(),
+            Unit::Temperature(unit) => unit.symbol(),
+            Unit::Pressure(unit) => unit.symbol(),
+            Unit::Mass(unit) => unit.symbol(),
+            Unit::Volume(unit) => unit.symbol(),
+        }
+    }
+
+    /// Returns alternative spellings accepted when parsing, e.g. `"metre"`
+    pub fn aliases(self) -> &'static [&'static str] {
+        match self {
+            Unit::Length(unit) => unit.aliases(),
+            Unit::Temperature(unit) => unit.aliases(),
+            Unit::Pressure(unit) => unit.aliases(),
+            Unit::Mass(unit) => unit.aliases(),
+            Unit::Volume(unit) => unit.aliases(),
+        }
+    }
+
+    /// Returns the equivalent unit used in `region`
+    ///
+    /// Units already used in the region are returned unchanged, so both meters
+    /// and kilometers are metric, while feet map to meters and miles to
+    /// kilometers. Imperial and US customary share their units except for
+    /// gallons; [`MeasurementSystem::Other`] leaves every unit unchanged.
+    pub fn in_region(self, region: Region) -> Unit {
+        use length::LengthUnit as L;
+        use mass::MassUnit as M;
+        use pressure::PressureUnit as P;
+        use temperature::TemperatureUnit as T;
+        use volume::VolumeUnit as V;
+
+        match (region, self) {
+            (Region::Metric, Unit::Length(L::Inches | L::Feet | L::Yards | L::UsSurveyFeet)) => {
+                L::Meters.into()
+            }
+            (Region::Metric, Unit::Length(L::Miles)) => L::Kilometers.into(),
+            (Region::Metric, Unit::Temperature(T::Fahrenheit | T::Delisle)) => T::Celsius.into(),
+            (Region::Metric, Unit::Pressure(P::Psi)) => P::Bar.into(),
+            (Region::Metric, Unit::Mass(M::Pounds)) => M::Kilograms.into(),
+            (Region::Metric, Unit::Mass(M::Tons)) => M::Tonnes.into(),
+            (Region::Metric, Unit::Volume(V::CubicFeet)) => V::CubicMeters.into(),
+            (Region::Metric, Unit::Volume(V::UsGallons | V::ImperialGallons)) => V::Liters.into(),
+            (
+                Region::Imperial | Region::US,
+                Unit::Length(L::Micrometers | L::Millimeters | L::Centimeters),
+            ) => L::Inches.into(),
+            (Region::Imperial | Region::US, Unit::Length(L::Meters)) => L::Feet.into(),
+            (Region::Imperial | Region::US, Unit::Length(L::Kilometers)) => L::Miles.into(),
+            (Region::Imperial | Region::US, Unit::Temperature(_)) => T::Fahrenheit.into(),
+            (Region::Imperial | Region::US, Unit::Pressure(_)) => P::Psi.into(),
+            (Region::Imperial | Region::US, Unit::Mass(M::Grams | M::Kilograms)) => {
+                M::Pounds.into()
+            }
+            (Region::Imperial | Region::US, Unit::Mass(M::Tonnes)) => M::Tons.into(),
+            (Region::Imperial | Region::US, Unit::Volume(V::CubicMeters)) => V::CubicFeet.into(),
+            (Region::Imperial, Unit::Volume(V::Liters)) => V::ImperialGallons.into(),
+            (Region::US, Unit::Volume(V::Liters)) => V::UsGallons.into(),
+            (_, unit) => unit,
+        }
+    }
+
+    /// Converts a value in this unit to the base unit of its category
+    ///
+    /// The base units are meters, Kelvin, Pascal, kilograms and cubic meters.
+    pub fn to_base(self, value: f64) -> f64 {
+        match self {
+            Unit::Length(unit) => value * unit.meters_per_unit(),
+            Unit::Temperature(unit) => unit.to_kelvin(value),
+            Unit::Pressure(unit) => value * unit.pascals_per_unit(),
+            Unit::Mass(unit) => value * unit.kilograms_per_unit(),
+            Unit::Volume(unit) => value * unit.cubic_meters_per_unit(),
+        }
+    }
+
+    /// Converts a value in the base unit of this category to this unit
+    pub fn from_base(self, value: f64) -> f64 {
+        match self {
+            Unit::Length(unit) => value / unit.meters_per_unit(),
+            Unit::Temperature(unit) => unit.from_kelvin(value),
+            Unit::Pressure(unit) => value / unit.pascals_per_unit(),
+            Unit::Mass(unit) => value / unit.kilograms_per_unit(),
+            Unit::Volume(unit) => value / unit.cubic_meters_per_unit(),
+        }
+    }
+}
+
+impl From<length::LengthUnit> for Unit {
+    fn from(unit: length::LengthUnit) -> Self {
+        Unit::Length(unit)
+    }
+}
+
+impl From<temperature::TemperatureUnit> for Unit {
+    fn from(unit: temperature::TemperatureUnit) -> Self {
+        Unit::Temperature(unit)
+    }
+}
+
+impl From<pressure::PressureUnit> for Unit {
+    fn from(unit: pressure::PressureUnit) -> Self {
+        Unit::Pressure(unit)
+    }
+}
+
+impl From<mass::MassUnit> for Unit {
+    fn from(unit: mass::MassUnit) -> Self {
+        Unit::Mass(unit)
+    }
+}
+
+impl From<volume::VolumeUnit> for Unit {
+    fn from(unit: volume::VolumeUnit) -> Self {
+        Unit::Volume(unit)
+    }
+}
+
+impl fmt::Display for Unit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.symbol())
+    }
+}
+
+/// Maps Unicode look-alikes in a unit symbol to the forms used by the parsers
+///
+/// `℃` and `℉` become `°C` and `°F`, the Kelvin sign becomes `K`, the Greek
+/// mu becomes the micro sign, and the masculine ordinal `º` and ring `˚`
+/// become the degree sign. Combining marks, zero-width characters and
+/// variation selectors, which often sneak in when pasting, are dropped.
+fn normalize_unit_symbol(s: &str) -> std::borrow::Cow<'_, str> {
+    if s.is_ascii() {
+        return std::borrow::Cow::Borrowed(s);
+    }
+
+    let mut normalized = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '\u{2103}' => normalized.push_str("°C"),
+            '\u{2109}' => normalized.push_str("°F"),
+            '\u{212a}' => normalized.push('K'),
+            '\u{3bc}' => normalized.push('µ'),
+            '\u{ba}' | '\u{2da}' => normalized.push('°'),
+            '\u{300}'..='\u{36f}'
+            | '\u{200b}'..='\u{200d}'
+            | '\u{fe00}'..='\u{fe0f}'
+            | '\u{feff}' => {}
+            c => normalized.push(c),
+        }
+    }
+    std::borrow::Cow::Owned(normalized)
+}
+
+impl FromStr for Unit {
+    type Err = Error;
+
+    /// Parses a unit from its symbol or one of its aliases, e.g. `"ft"` or `"feet"`
+    ///
+    /// Failures are counted in [`metrics`].
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_unit(s).map_err(failed)
+    }
+}
+
+/// Parses a unit like [`Unit::from_str`] without counting failures, for
+/// callers that merely probe whether a word is a unit
+fn parse_unit(s: &str) -> Result<Unit, Error> {
+    s.parse::<length::LengthUnit>()
+        .map(Unit::from)
+        .or_else(|_| s.parse::<temperature::TemperatureUnit>().map(Unit::from))
+        .or_else(|_| s.parse::<pressure::PressureUnit>().map(Unit::from))
+        .or_else(|_| s.parse::<mass::MassUnit>().map(Unit::from))
+        .or_else(|_| s.parse::<volume::VolumeUnit>().map(Unit::from))
+}
+
+/// A value together with its unit
+///
+/// # Examples
+///
+/// ```
+/// use conversion_lib::length::LengthUnit;
+/// use conversion_lib::Quantity;
+///
+/// let height = Quantity::new(2.5, LengthUnit::Meters);
+/// assert_eq!(height.unit.symbol(), "m");
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct Quantity {
+    pub value: f64,
+    pub unit: Unit,
+}
+
+impl Quantity {
+    /// Creates a new quantity
+    pub fn new(value: f64, unit: impl Into<Unit>) -> Self {
+        Quantity {
+            value,
+            unit: unit.into(),
+        }
+    }
+}
+
+impl Quantity {
+    /// Returns the value expressed in the base unit of its category
+    pub fn base_value(&self) -> f64 {
+        self.unit.to_base(self.value)
+    }
+}
+
+impl Quantity {
+    /// Converts the quantity to another unit of the same category
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::CategoryMismatch`] if `unit` belongs to another category
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use conversion_lib::length::LengthUnit;
+    /// use conversion_lib::{MeasurementSystem, Quantity};
+    ///
+    /// let feet = Quantity::new(3.0, LengthUnit::Meters).to(LengthUnit::Feet).unwrap();
+    /// assert_eq!(feet.system(), MeasurementSystem::Imperial);
+    /// ```
+    pub fn to(&self, unit: impl Into<Unit>) -> Result<Quantity, Error> {
+        let unit = unit.into();
+        Ok(Quantity::new(convert(self.value, self.unit, unit)?, unit))
+    }
+
+    /// Returns the measurement system of the quantity's unit
+    pub fn system(&self) -> MeasurementSystem {
+        system(self.unit)
+    }
+}
+
+impl Quantity {
+    /// Attaches a display precision without rounding the stored value
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use conversion_lib::length::LengthUnit;
+    /// use conversion_lib::Quantity;
+    ///
+    /// let feet = Quantity::new(1.0, LengthUnit::Meters)
+    ///     .with_display_precision(2)
+    ///     .to(LengthUnit::Feet)
+    ///     .unwrap();
+    /// assert_eq!(feet.to_string(), "3.28 ft");
+    /// ```
+    pub fn with_display_precision(self, precision: usize) -> RoundedQuantity {
+        RoundedQuantity {
+            quantity: self,
+            precision,
+        }
+    }
+}
+
+/// Compares quantities of the same category by their base values
+///
+/// `1 m` and `100 cm` are equal; quantities from different categories never
+/// are. Compare `value` and `unit` directly when the representation matters.
+///
+/// # Examples
+///
+/// ```
+/// use conversion_lib::length::LengthUnit;
+/// use conversion_lib::Quantity;
+///
+/// let meter = Quantity::new(1.0, LengthUnit::Meters);
+/// assert_eq!(meter, Quantity::new(100.0, LengthUnit::Centimeters));
+/// ```
+impl PartialEq for Quantity {
+    fn eq(&self, other: &Self) -> bool {
+        self.unit.category() == other.unit.category() && self.base_value() == other.base_value()
+    }
+}
+
+/// Orders quantities of the same category by their base values
+///
+/// Quantities from different categories are unordered and compare as `None`,
+/// consistent with [`PartialEq`], so `1 m` and `100 cm` are both equal and
+/// ordered as [`Equal`](std::cmp::Ordering::Equal).
+///
+/// # Examples
+///
+/// ```
+/// use conversion_lib::length::LengthUnit;
+/// use conversion_lib::Quantity;
+///
+/// assert!(Quantity::new(1.0, LengthUnit::Meters) > Quantity::new(3.0, LengthUnit::Feet));
+/// ```
+impl PartialOrd for Quantity {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        if self.unit.category() != other.unit.category() {
+            return None;
+        }
+        self.base_value().partial_cmp(&other.base_value())
+    }
+}
+
+impl fmt::Display for Quantity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {}", self.value, self.unit)
+    }
+}
+
+/// A quantity that is rounded only when displayed
+///
+/// Conversions keep working on the full-precision value, so rounding never
+/// accumulates along a chain.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RoundedQuantity {
+    /// The full-precision quantity
+    pub quantity: Quantity,
+    /// Number of decimal places shown by `Display`
+    pub precision: usize,
+}
+
+impl RoundedQuantity {
+    /// Converts the underlying quantity, keeping the display precision
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::CategoryMismatch`] if `unit` belongs to another category
+    pub fn to(&self, unit: impl Into<Unit>) -> Result<RoundedQuantity, Error> {
+        Ok(RoundedQuantity {
+            quantity: self.quantity.to(unit)?,
+            precision: self.precision,
+        })
+    }
+}
+
+/// A [`RoundedQuantity`] whose precision was taken from the input text
+///
+/// Parsing `"1.50 m"` records two decimal places, which are kept through
+/// conversions so the output reflects the precision the user entered.
+pub type PreciseQuantity = RoundedQuantity;
+
+impl FromStr for RoundedQuantity {
+    type Err = Error;
+
+    /// Parses `"<value> <unit>"`, using the decimal places written in the
+    /// value as the display precision
+    ///
+    /// In exponent notation the exponent shifts the decimal point, so
+    /// `"1.5e3 m"` has no decimal places and `"1.5e-3 m"` has four.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use conversion_lib::length::LengthUnit;
+    /// use conversion_lib::PreciseQuantity;
+    ///
+    /// let length: PreciseQuantity = "1.50 m".parse().unwrap();
+    /// assert_eq!(length.to(LengthUnit::Feet).unwrap().to_string(), "4.92 ft");
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (value, unit) = s
+            .trim()
+            .split_once(char::is_whitespace)
+            .ok_or_else(|| Error::Parse(format!("expected \"<value> <unit>\", got {:?}", s)))?;
+
+        let number: f64 = value
+            .parse()
+            .map_err(|_| Error::Parse(format!("invalid number {:?}", value)))?;
+        let (mantissa, exponent) = value.split_once(['e', 'E']).unwrap_or((value, "0"));
+        let decimals = mantissa
+            .split_once('.')
+            .map_or(0, |(_, decimals)| decimals.len());
+        // An exponent too long for i64 has already made the number 0 or infinite
+        let exponent: i64 = exponent.parse().unwrap_or(0);
+        let precision = usize::try_from((decimals as i64).saturating_sub(exponent)).unwrap_or(0);
+
+        Ok(RoundedQuantity {
+            quantity: Quantity::new(number, unit.trim().parse::<Unit>()?),
+            precision,
+        })
+    }
+}
+
+impl fmt::Display for RoundedQuantity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{:.*} {}",
+            self.precision, self.quantity.value, self.quantity.unit
+        )
+    }
+}
+
+/// Scales a quantity by a factor
+///
+/// Returns [`Error::InvalidOperationForScale`] for interval-scale units,
+/// since doubling 20 °C does not give a meaningful 40 °C.
+///
+/// # Examples
+///
+/// ```
+/// use conversion_lib::length::LengthUnit;
+/// use conversion_lib::temperature::TemperatureUnit;
+/// use conversion_lib::Quantity;
+///
+/// let doubled = (Quantity::new(2.0, LengthUnit::Meters) * 2.0).unwrap();
+/// assert_eq!(doubled, Quantity::new(4.0, LengthUnit::Meters));
+/// assert!((Quantity::new(20.0, TemperatureUnit::Celsius) * 2.0).is_err());
+/// ```
+impl std::ops::Mul<f64> for Quantity {
+    type Output = Result<Quantity, Error>;
+
+    fn mul(self, factor: f64) -> Self::Output {
+        if scale_type(self.unit) == ScaleType::Interval {
+            return Err(Error::InvalidOperationForScale(self.unit));
+        }
+        Ok(Quantity::new(self.value * factor, self.unit))
+    }
+}
+
+/// Divides a quantity by a divisor
+///
+/// Returns [`Error::InvalidOperationForScale`] for interval-scale units and
+/// [`Error::DivisionByZero`] when `divisor` is zero.
+impl std::ops::Div<f64> for Quantity {
+    type Output = Result<Quantity, Error>;
+
+    fn div(self, divisor: f64) -> Self::Output {
+        if scale_type(self.unit) == ScaleType::Interval {
+            return Err(Error::InvalidOperationForScale(self.unit));
+        }
+        if divisor == 0.0 {
+            return Err(Error::DivisionByZero);
+        }
+        Ok(Quantity::new(self.value / divisor, self.unit))
+    }
+}
+
+/// Serialized as `{"value": 100.0, "unit": "m"}`, with the unit validated on input
+#[cfg(feature = "serde")]
+mod quantity_serde {
+    use super::Quantity;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    #[derive(Serialize)]
+    struct QuantityRef<'a> {
+        value: f64,
+        unit: &'a str,
+    }
+
+    #[derive(Deserialize)]
+    struct QuantityOwned {
+        value: f64,
+        unit: String,
+    }
+
+    impl Serialize for Quantity {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            QuantityRef {
+                value: self.value,
+                unit: self.unit.symbol(),
+            }
+            .serialize(serializer)
+        }
+    }
+
+    impl<'de> Deserialize<'de> for Quantity {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            let repr = QuantityOwned::deserialize(deserializer)?;
+            let unit = repr.unit.parse().map_err(serde::de::Error::custom)?;
+            Ok(Quantity {
+                value: repr.value,
+                unit,
+            })
+        }
+    }
+}
+
+/// A running sum of quantities from one category
+///
+/// Each added quantity is converted to the base unit of the category, so
+/// values in different units can be mixed freely.
+///
+/// # Examples
+///
+/// ```
+/// use conversion_lib::length::LengthUnit;
+/// use conversion_lib::{Category, Quantity, RunningTotal};
+///
+/// let mut total = RunningTotal::new(Category::Length);
+/// total.add(Quantity::new(1.0, LengthUnit::Kilometers)).unwrap();
+/// total.add(Quantity::new(500.0, LengthUnit::Meters)).unwrap();
+/// assert_eq!(total.total(LengthUnit::Meters), 1500.0);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct RunningTotal {
+    category: Category,
+    base_sum: f64,
+}
+
+impl RunningTotal {
+    /// Creates an empty running total for the given category
+    pub fn new(category: Category) -> Self {
+        RunningTotal {
+            category,
+            base_sum: 0.0,
+        }
+    }
+
+    /// Adds a quantity to the total
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::CategoryMismatch`] if the quantity is from another category
+    pub fn add(&mut self, quantity: Quantity) -> Result<(), Error> {
+        if quantity.unit.category() != self.category {
+            return Err(Error::CategoryMismatch {
+                from: quantity.unit.category(),
+                to: self.category,
+            });
+        }
+
+        self.base_sum += quantity.base_value();
+        Ok(())
+    }
+
+    /// Returns the total expressed in the given unit
+    ///
+    /// # Panics
+    ///
+    /// Panics if `unit` is from another category than the total
+    pub fn total(&self, unit: impl Into<Unit>) -> f64 {
+        let unit = unit.into();
+        assert_eq!(
+            unit.category(),
+            self.category,
+            "unit is from another category"
+        );
+        unit.from_base(self.base_sum)
+    }
+}
+
+/// Streaming statistics over converted values
+///
+/// Uses Welford's algorithm, so mean and variance stay accurate without
+/// storing the values. All values are recorded in the `to` unit of the first
+/// [`push`](RunningStats::push).
+///
+/// # Examples
+///
+/// ```
+/// use conversion_lib::temperature::TemperatureUnit;
+/// use conversion_lib::RunningStats;
+///
+/// let mut stats = RunningStats::new();
+/// for reading in [68.0, 77.0, 86.0] {
+///     stats.push(reading, TemperatureUnit::Fahrenheit, TemperatureUnit::Celsius).unwrap();
+/// }
+/// assert!((stats.mean().unwrap() - 25.0).abs() < 1e-9);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct RunningStats {
+    unit: Option<Unit>,
+    count: u64,
+    mean: f64,
+    sum_squared_deviations: f64,
+    min: f64,
+    max: f64,
+}
+
+impl RunningStats {
+    /// Creates empty statistics
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Converts a raw reading and adds it to the statistics
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::CategoryMismatch`] if the units belong to different categories
+    /// * [`Error::InvalidInput`] if `to` differs from the unit of earlier readings
+    pub fn push(
+        &mut self,
+        raw_value: f64,
+        from: impl Into<Unit>,
+        to: impl Into<Unit>,
+    ) -> Result<(), Error> {
+        let to = to.into();
+        if let Some(unit) = self.unit.filter(|&unit| unit != to) {
+            return Err(Error::InvalidInput(format!(
+                "statistics are recorded in {}, not {}",
+                unit, to
+            )));
+        }
+        let value = convert(raw_value, from, to)?;
+
+        self.unit = Some(to);
+        self.count += 1;
+        let delta = value - self.mean;
+        self.mean += delta / self.count as f64;
+        self.sum_squared_deviations += delta * (value - self.mean);
+        if self.count == 1 {
+            (self.min, self.max) = (value, value);
+        } else {
+            self.min = self.min.min(value);
+            self.max = self.max.max(value);
+        }
+        Ok(())
+    }
+
+    /// Returns the unit the statistics are recorded in, once a value was pushed
+    pub fn unit(&self) -> Option<Unit> {
+        self.unit
+    }
+
+    /// Returns the number of values pushed
+    pub fn count(&self) -> u64 {
+        self.count
+    }
+
+    /// Returns the mean, or `None` when empty
+    pub fn mean(&self) -> Option<f64> {
+        (self.count > 0).then_some(self.mean)
+    }
+
+    /// Returns the smallest value, or `None` when empty
+    pub fn min(&self) -> Option<f64> {
+        (self.count > 0).then_some(self.min)
+    }
+
+    /// Returns the largest value, or `None` when empty
+    pub fn max(&self) -> Option<f64> {
+        (self.count > 0).then_some(self.max)
+    }
+
+    /// Returns the sample variance, or `None` with fewer than two values
+    pub fn variance(&self) -> Option<f64> {
+        (self.count > 1).then(|| self.sum_squared_deviations / (self.count - 1) as f64)
+    }
+
+    /// Returns the sample standard deviation, or `None` with fewer than two values
+    pub fn std_dev(&self) -> Option<f64> {
+        self.variance().map(f64::sqrt)
+    }
+}
+
+/// Two linked fields showing the same value in different units
+///
+/// The value is stored once in the base unit, so setting either field through
+/// [`LinkedPair::set_from`] keeps the other in step without accumulating
+/// round-trip error.
+///
+/// # Examples
+///
+/// ```
+/// use conversion_lib::length::LengthUnit;
+/// use conversion_lib::LinkedPair;
+///
+/// let mut pair = LinkedPair::new(LengthUnit::Meters, LengthUnit::Kilometers).unwrap();
+/// pair.set_from(LengthUnit::Meters, 2500.0).unwrap();
+/// assert_eq!(pair.get(LengthUnit::Kilometers), 2.5);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct LinkedPair {
+    first: Unit,
+    second: Unit,
+    base_value: f64,
+}
+
+impl LinkedPair {
+    /// Creates a linked pair of fields, both starting at zero
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::CategoryMismatch`] if the units are from different categories
+    pub fn new(first: impl Into<Unit>, second: impl Into<Unit>) -> Result<Self, Error> {
+        let (first, second) = (first.into(), second.into());
+        check_same_category(first, second)?;
+        Ok(LinkedPair {
+            first,
+            second,
+            base_value: first.to_base(0.0),
+        })
+    }
+
+    /// Sets the shared value from a field edited in `unit`
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::CategoryMismatch`] if `unit` is from another category
+    pub fn set_from(&mut self, unit: impl Into<Unit>, value: f64) -> Result<(), Error> {
+        let unit = unit.into();
+        check_same_category(unit, self.first)?;
+        self.base_value = unit.to_base(value);
+        Ok(())
+    }
+
+    /// Returns the shared value expressed in `unit`
+    ///
+    /// # Panics
+    ///
+    /// Panics if `unit` is from another category than the pair
+    pub fn get(&self, unit: impl Into<Unit>) -> f64 {
+        let unit = unit.into();
+        assert_eq!(
+            unit.category(),
+            self.first.category(),
+            "unit is from another category"
+        );
+        unit.from_base(self.base_value)
+    }
+
+    /// Returns the values of the first and second fields
+    pub fn values(&self) -> (f64, f64) {
+        (self.get(self.first), self.get(self.second))
+    }
+}
+
+/// Returns the SI dimension of a unit as base-unit exponents
+///
+/// The exponents are ordered length, mass, time, electric current,
+/// temperature, amount of substance and luminous intensity, so pascals
+/// (kg·m⁻¹·s⁻²) are `[-1, 1, -2, 0, 0, 0, 0]`.
+///
+/// # Examples
+///
+/// ```
+/// use conversion_lib::dimension;
+/// use conversion_lib::pressure::PressureUnit;
+///
+/// assert_eq!(dimension(PressureUnit::Psi), [-1, 1, -2, 0, 0, 0, 0]);
+/// ```
+pub fn dimension(unit: impl Into<Unit>) -> [i8; 7] {
+    match unit.into().category() {
+        Category::Length => [1, 0, 0, 0, 0, 0, 0],
+        Category::Temperature => [0, 0, 0, 0, 1, 0, 0],
+        Category::Pressure => [-1, 1, -2, 0, 0, 0, 0],
+        Category::Mass => [0, 1, 0, 0, 0, 0, 0],
+        Category::Volume => [3, 0, 0, 0, 0, 0, 0],
+    }
+}
+
+/// Converts a value between any two units of the same dimension
+///
+/// Converting a unit to itself returns `value` bit-for-bit without any
+/// arithmetic, so subnormals, signed zeros and NaN payloads survive.
+///
+/// # Errors
+///
+/// Returns [`Error::CategoryMismatch`] if the units have different
+/// [`dimension`]s
+///
+/// # Examples
+///
+/// ```
+/// use conversion_lib::length::LengthUnit;
+/// use conversion_lib::temperature::TemperatureUnit;
+/// use conversion_lib::convert;
+///
+/// let feet = convert(1.0, LengthUnit::Miles, LengthUnit::Feet).unwrap();
+/// assert!((feet - 5280.0).abs() < 1e-9);
+///
+/// assert!(convert(1.0, LengthUnit::Miles, TemperatureUnit::Kelvin).is_err());
+/// ```
+pub fn convert(value: f64, from: impl Into<Unit>, to: impl Into<Unit>) -> Result<f64, Error> {
+    let (from, to) = (from.into(), to.into());
+    if dimension(from) != dimension(to) {
+        return Err(failed(Error::CategoryMismatch {
+            from: from.category(),
+            to: to.category(),
+        }));
+    }
+    // Routing through the base unit would round affine scales and subnormals
+    if from == to {
+        return Ok(value);
+    }
+    Ok(to.from_base(from.to_base(value)))
+}
+
+/// Reports whether a conversion between two units is exact
+///
+/// A conversion is exact when both units have an exact rational factor in
+/// this crate, such as [`LengthUnit::meters_per_unit_ratio`]: the foot is
+/// stored as exactly 381/1250 m, so [`length::convert_rational`] gives exact
+/// results and [`convert`] only adds `f64` rounding. Every other factor is a
+/// rounded `f64` constant, and helpers such as [`mass::kg_to_pounds`] even use
+/// 2.20462, so those conversions are reported as approximate. Converting a
+/// unit to itself is always exact.
+///
+/// [`LengthUnit::meters_per_unit_ratio`]: length::LengthUnit::meters_per_unit_ratio
+///
+/// # Errors
+///
+/// Returns [`Error::CategoryMismatch`] if the units belong to different categories
+///
+/// # Examples
+///
+/// ```
+/// use conversion_lib::is_exact;
+/// use conversion_lib::length::LengthUnit;
+/// use conversion_lib::mass::MassUnit;
+///
+/// assert!(is_exact(LengthUnit::Meters, LengthUnit::Feet).unwrap());
+/// assert!(!is_exact(MassUnit::Kilograms, MassUnit::Pounds).unwrap());
+/// ```
+pub fn is_exact(from: impl Into<Unit>, to: impl Into<Unit>) -> Result<bool, Error> {
+    let (from, to) = (from.into(), to.into());
+    check_same_category(from, to)?;
+    Ok(from == to || (exact_ratio(from).is_some() && exact_ratio(to).is_some()))
+}
+
+/// Returns the exact rational base-unit factor of a unit, if it has one
+fn exact_ratio(unit: Unit) -> Option<(i64, i64)> {
+    match unit {
+        Unit::Length(unit) => Some(unit.meters_per_unit_ratio()),
+        _ => None,
+    }
+}
+
+/// A unit category that can be boxed and chosen at runtime
+///
+/// Each category module provides an implementation, such as
+/// [`length::LengthCategory`]; [`Category::converter`] returns the one for a
+/// [`Category`] value. Conversions go through [`convert`], after checking that
+/// both units belong to this category.
+///
+/// # Examples
+///
+/// ```
+/// use conversion_lib::length::LengthUnit;
+/// use conversion_lib::{Category, CategoryConverter};
+///
+/// let converter: Box<dyn CategoryConverter> = Category::Length.converter();
+/// let feet = converter
+///     .convert(1.0, LengthUnit::Meters.into(), LengthUnit::Feet.into())
+///     .unwrap();
+/// assert!((feet - 3.28084).abs() < 1e-5);
+/// ```
+pub trait CategoryConverter: Send + Sync {
+    /// Returns the category this converter handles
+    fn category(&self) -> Category;
+
+    /// Returns every unit of the category
+    fn units(&self) -> Vec<Unit> {
+        Unit::all()
+            .into_iter()
+            .filter(|unit| unit.category() == self.category())
+            .collect()
+    }
+
+    /// Converts a value between two units of the category
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::CategoryMismatch`] if either unit belongs to another
+    /// category
+    fn convert(&self, value: f64, from: Unit, to: Unit) -> Result<f64, Error> {
+        let base = self.category().base_unit();
+        check_same_category(from, base)?;
+        check_same_category(base, to)?;
+        convert(value, from, to)
+    }
+}
+
+impl Category {
+    /// Returns the boxed [`CategoryConverter`] for this category
+    pub fn converter(self) -> Box<dyn CategoryConverter> {
+        match self {
+            Category::Length => Box::new(length::LengthCategory),
+            Category::Temperature => Box::new(temperature::TemperatureCategory),
+            Category::Pressure => Box::new(pressure::PressureCategory),
+            Category::Mass => Box::new(mass::MassCategory),
+            Category::Volume => Box::new(volume::VolumeCategory),
+        }
+    }
+}
+
+/// A source of units that [`convert_any`] can convert between
+///
+/// Implement this to plug domain-specific units into the generic converter
+/// and add the implementation with [`register_provider`]. Every unit symbol
+/// belongs to a named category, and conversions go through a base unit that
+/// the provider chooses for each of its categories.
+pub trait UnitProvider: Send + Sync {
+    /// Returns the symbols of every unit the provider supports
+    fn units(&self) -> Vec<String>;
+
+    /// Returns the category name of a unit, or `None` if the symbol is unknown
+    fn category(&self, symbol: &str) -> Option<String>;
+
+    /// Converts a value in the given unit to its category's base unit
+    fn convert_to_base(&self, value: f64, symbol: &str) -> Option<f64>;
+
+    /// Converts a value in its category's base unit to the given unit
+    fn convert_from_base(&self, value: f64, symbol: &str) -> Option<f64>;
+}
+
+/// The units built into this crate, exposed as a [`UnitProvider`]
+///
+/// Category names are the [`Category`] variant names, e.g. `"Length"`.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct BuiltinUnits;
+
+impl UnitProvider for BuiltinUnits {
+    fn units(&self) -> Vec<String> {
+        Unit::all()
+            .into_iter()
+            .map(|unit| unit.symbol().to_string())
+            .collect()
+    }
+
+    fn category(&self, symbol: &str) -> Option<String> {
+        let unit = parse_unit(symbol).ok()?;
+        Some(format!("{:?}", unit.category()))
+    }
+
+    fn convert_to_base(&self, value: f64, symbol: &str) -> Option<f64> {
+        Some(parse_unit(symbol).ok()?.to_base(value))
+    }
+
+    fn convert_from_base(&self, value: f64, symbol: &str) -> Option<f64> {
+        Some(parse_unit(symbol).ok()?.from_base(value))
+    }
+}
+
+static PROVIDERS: RwLock<Vec<Box<dyn UnitProvider>>> = RwLock::new(Vec::new());
+
+/// Adds a provider that [`convert_any`] consults after the built-in units
+///
+/// Providers are process-wide and consulted in registration order.
+pub fn register_provider(provider: Box<dyn UnitProvider>) {
+    PROVIDERS
+        .write()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+        .push(provider);
+}
+
+/// Converts between unit symbols from the built-in units or any registered provider
+///
+/// Both units must come from the same provider and share a category; the
+/// built-in units are tried first, then providers in registration order.
+///
+/// # Errors
+///
+/// Returns [`Error::UnknownUnit`] if no provider knows a symbol and
+/// [`Error::NoConversionPath`] if the units are in different categories or
+/// come from different providers
+///
+/// # Examples
+///
+/// ```
+/// use conversion_lib::convert_any;
+///
+/// let feet = convert_any(1.0, "mi", "ft").unwrap();
+/// assert!((feet - 5280.0).abs() < 1e-9);
+/// ```
+pub fn convert_any(value: f64, from: &str, to: &str) -> Result<f64, Error> {
+    let no_path = || Error::NoConversionPath {
+        from: from.to_string(),
+        to: to.to_string(),
+    };
+    let registered = PROVIDERS
+        .read()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    let builtin: &dyn UnitProvider = &BuiltinUnits;
+    let providers = std::iter::once(builtin).chain(registered.iter().map(|p| p.as_ref()));
+
+    let (mut knows_from, mut knows_to) = (false, false);
+    for provider in providers {
+        match (provider.category(from), provider.category(to)) {
+            (Some(a), Some(b)) if a == b => {
+                let result = provider
+                    .convert_to_base(value, from)
+                    .and_then(|base| provider.convert_from_base(base, to));
+                return result.ok_or_else(|| failed(no_path()));
+            }
+            (a, b) => {
+                knows_from |= a.is_some();
+                knows_to |= b.is_some();
+            }
+        }
+    }
+
+    let error = if !knows_from {
+        Error::UnknownUnit(from.to_string())
+    } else if !knows_to {
+        Error::UnknownUnit(to.to_string())
+    } else {
+        no_path()
+    };
+    Err(failed(error))
+}
+
+/// How a conversion maps an input value to its result
+///
+/// Most unit pairs are related by a scale factor, temperatures by a factor
+/// and an offset, and quantities such as period and frequency by inversion.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ConversionKind {
+    /// `y = x × factor`
+    Linear { factor: f64 },
+    /// `y = x × factor + offset`
+    Affine { factor: f64, offset: f64 },
+    /// `y = constant / x`
+    Reciprocal { constant: f64 },
+}
+
+impl ConversionKind {
+    /// Describes the conversion [`convert`] performs between two units
+    ///
+    /// Built-in units are always related linearly or affinely.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::CategoryMismatch`] if the units belong to different categories
+    pub fn between(from: impl Into<Unit>, to: impl Into<Unit>) -> Result<Self, Error> {
+        let (from, to) = (from.into(), to.into());
+        let offset = convert(0.0, from, to)?;
+        let factor = convert(1.0, from, to)? - offset;
+        Ok(if offset == 0.0 {
+            ConversionKind::Linear { factor }
+        } else {
+            ConversionKind::Affine { factor, offset }
+        })
+    }
+
+    /// Applies the conversion to a value
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::DivisionByZero`] if a reciprocal conversion is given zero
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use conversion_lib::ConversionKind;
+    ///
+    /// let invert = ConversionKind::Reciprocal { constant: 1.0 };
+    /// assert_eq!(invert.apply(4.0).unwrap(), 0.25);
+    /// assert!(invert.apply(0.0).is_err());
+    /// ```
+    pub fn apply(self, value: f64) -> Result<f64, Error> {
+        match self {
+            ConversionKind::Linear { factor } => Ok(value * factor),
+            ConversionKind::Affine { factor, offset } => Ok(value * factor + offset),
+            ConversionKind::Reciprocal { constant } => {
+                if value == 0.0 {
+                    return Err(Error::DivisionByZero);
+                }
+                Ok(constant / value)
+            }
+        }
+    }
+}
+
+/// Dumps the factor of every linear unit pair as CSV
+///
+/// The output starts with a `category,from,to,factor` header followed by one
+/// row per ordered pair of distinct units, where `to = from × factor`.
+/// Pairs related by an offset, i.e. the temperature scales, are omitted;
+/// [`ConversionKind::between`] describes those.
+///
+/// # Examples
+///
+/// ```
+/// use conversion_lib::export_factors_csv;
+///
+/// let csv = export_factors_csv();
+/// assert!(csv.starts_with("category,from,to,factor\n"));
+/// assert!(csv.contains("\nLength,km,m,1000\n"));
+/// ```
+pub fn export_factors_csv() -> String {
+    let mut csv = String::from("category,from,to,factor\n");
+    let units = Unit::all();
+    for &from in &units {
+        for &to in &units {
+            if from == to || from.category() != to.category() {
+                continue;
+            }
+            if let Ok(ConversionKind::Linear { factor }) = ConversionKind::between(from, to) {
+                csv.push_str(&format!(
+                    "{:?},{},{},{}\n",
+                    from.category(),
+                    from,
+                    to,
+                    factor
+                ));
+            }
+        }
+    }
+    csv
+}
+
+/// Converts an SI value to every imperial and US customary unit of its category
+///
+/// `value` is in the base unit of `category` (see [`Category::base_unit`]).
+/// Units are listed in [`Unit::all`] order; [`deprecated`] units are left out.
+///
+/// # Examples
+///
+/// ```
+/// use conversion_lib::{to_all_imperial, Category};
+///
+/// let lengths = to_all_imperial(1609.344, Category::Length);
+/// assert!(lengths.contains(&("mi", 1.0)));
+/// ```
+pub fn to_all_imperial(value: f64, category: Category) -> Vec<(&'static str, f64)> {
+    let base = category.base_unit();
+    Unit::all()
+        .into_iter()
+        .filter(|&unit| unit.category() == category && !deprecated(unit))
+        .filter(|&unit| {
+            matches!(
+                system(unit),
+                MeasurementSystem::Imperial | MeasurementSystem::US
+            )
+        })
+        .map(|unit| (unit.symbol(), unit.from_base(base.to_base(value))))
+        .collect()
+}
+
+/// A linear calibration `output = scale × input + offset`
+///
+/// Generalises the temperature-style conversions for custom sensors; store
+/// several under names in a [`CalibrationSet`].
+///
+/// # Examples
+///
+/// ```
+/// use conversion_lib::AffineConversion;
+///
+/// // 4–20 mA loop mapped to 0–10 bar
+/// let sensor = AffineConversion::new(0.625, -2.5);
+/// assert_eq!(sensor.apply(12.0), 5.0);
+/// assert_eq!(sensor.invert(5.0), 12.0);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AffineConversion {
+    scale: f64,
+    offset: f64,
+}
+
+impl AffineConversion {
+    /// Creates a calibration from its scale and offset
+    ///
+    /// # Panics
+    ///
+    /// Panics if `scale` is zero or either argument is not finite, since the
+    /// conversion could not be inverted
+    pub fn new(scale: f64, offset: f64) -> Self {
+        assert!(
+            scale != 0.0 && scale.is_finite() && offset.is_finite(),
+            "scale must be non-zero and both coefficients finite"
+        );
+        AffineConversion { scale, offset }
+    }
+
+    /// Returns the scale factor
+    pub fn scale(&self) -> f64 {
+        self.scale
+    }
+
+    /// Returns the offset
+    pub fn offset(&self) -> f64 {
+        self.offset
+    }
+
+    /// Maps an input to its output
+    pub fn apply(&self, x: f64) -> f64 {
+        self.scale * x + self.offset
+    }
+
+    /// Maps an output back to the input that produces it
+    pub fn invert(&self, y: f64) -> f64 {
+        (y - self.offset) / self.scale
+    }
+}
+
+impl From<AffineConversion> for ConversionKind {
+    fn from(conversion: AffineConversion) -> Self {
+        ConversionKind::Affine {
+            factor: conversion.scale,
+            offset: conversion.offset,
+        }
+    }
+}
+
+/// Named [`AffineConversion`]s, such as the calibrations of installed sensors
+///
+/// # Examples
+///
+/// ```
+/// use conversion_lib::{AffineConversion, CalibrationSet};
+///
+/// let mut sensors = CalibrationSet::new();
+/// sensors.register("TT-101", AffineConversion::new(1.02, -0.4));
+/// let celsius = sensors.apply("TT-101", 25.0).unwrap();
+/// assert!((celsius - 25.1).abs() < 1e-12);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct CalibrationSet {
+    calibrations: HashMap<String, AffineConversion>,
+}
+
+impl CalibrationSet {
+    /// Creates an empty set
+    pub fn new() -> Self {
+        CalibrationSet::default()
+    }
+
+    /// Stores a calibration under `name`, returning the one it replaces
+    pub fn register(
+        &mut self,
+        name: impl Into<String>,
+        conversion: AffineConversion,
+    ) -> Option<AffineConversion> {
+        self.calibrations.insert(name.into(), conversion)
+    }
+
+    /// Returns the calibration stored under `name`
+    pub fn get(&self, name: &str) -> Option<AffineConversion> {
+        self.calibrations.get(name).copied()
+    }
+
+    /// Applies the calibration stored under `name`
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidInput`] if no calibration has that name
+    pub fn apply(&self, name: &str, x: f64) -> Result<f64, Error> {
+        Ok(self.lookup(name)?.apply(x))
+    }
+
+    /// Inverts the calibration stored under `name`
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidInput`] if no calibration has that name
+    pub fn invert(&self, name: &str, y: f64) -> Result<f64, Error> {
+        Ok(self.lookup(name)?.invert(y))
+    }
+
+    fn lookup(&self, name: &str) -> Result<AffineConversion, Error> {
+        self.get(name)
+            .ok_or_else(|| Error::InvalidInput(format!("no calibration named {:?}", name)))
+    }
+}
+
+/// A reusable sequence of unit conversions
+///
+/// Steps are added with [`then`](Pipeline::then) and run in order. Each step
+/// must start in the unit the previous one ended in. An empty pipeline
+/// returns values unchanged.
+///
+/// # Examples
+///
+/// ```
+/// use conversion_lib::length::LengthUnit;
+/// use conversion_lib::Pipeline;
+///
+/// let pipeline = Pipeline::new()
+///     .then(LengthUnit::Meters, LengthUnit::Feet)
+///     .then(LengthUnit::Feet, LengthUnit::Inches);
+/// let inches = pipeline.apply(1.0).unwrap();
+/// assert!((inches - 39.37007874015748).abs() < 1e-9);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Pipeline {
+    steps: Vec<(Unit, Unit)>,
+}
+
+impl Pipeline {
+    /// Creates a pipeline with no steps
+    pub fn new() -> Self {
+        Pipeline::default()
+    }
+
+    /// Appends a conversion step
+    pub fn then(mut self, from: impl Into<Unit>, to: impl Into<Unit>) -> Self {
+        self.steps.push((from.into(), to.into()));
+        self
+    }
+
+    /// Runs every step on a single value
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidInput`] if a step does not start in the unit
+    /// the previous step ended in and [`Error::CategoryMismatch`] if a step
+    /// converts between categories
+    pub fn apply(&self, value: f64) -> Result<f64, Error> {
+        self.validate()?;
+        self.run(value)
+    }
+
+    /// Runs every step on each value
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`apply`](Pipeline::apply); they depend only
+    /// on the steps, so either every value converts or none do.
+    pub fn apply_slice(&self, values: &[f64]) -> Result<Vec<f64>, Error> {
+        self.validate()?;
+        values.iter().map(|&value| self.run(value)).collect()
+    }
+
+    fn run(&self, value: f64) -> Result<f64, Error> {
+        self.steps
+            .iter()
+            .try_fold(value, |value, &(from, to)| convert(value, from, to))
+    }
+
+    fn validate(&self) -> Result<(), Error> {
+        for pair in self.steps.windows(2) {
+            let ((_, previous), (next, _)) = (pair[0], pair[1]);
+            if previous != next {
+                return Err(Error::InvalidInput(format!(
+                    "step from {} does not follow a step ending in {}",
+                    next, previous
+                )));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Record of a single conversion for audit logs
+#[derive(Debug, Clone, PartialEq)]
+pub struct Provenance {
+    /// Value and unit that were converted
+    pub input: Quantity,
+    /// Converted value and unit
+    pub output: Quantity,
+    /// Multiplier applied to the input value
+    pub factor: f64,
+    /// Offset added after multiplying, non-zero only between temperature scales
+    pub offset: f64,
+    /// Human-readable form of the conversion, e.g. `"y = x × 3.28084"`
+    pub formula: String,
+    /// When the conversion was performed
+    pub timestamp: std::time::SystemTime,
+}
+
+/// Converts a value and records how the result was obtained
+///
+/// Every supported conversion is affine, so it is described by the factor
+/// and offset of `y = x × factor + offset`.
+///
+/// # Errors
+///
+/// Returns [`Error::CategoryMismatch`] if the units belong to different categories
+///
+/// # Examples
+///
+/// ```
+/// use conversion_lib::convert_with_provenance;
+/// use conversion_lib::temperature::TemperatureUnit;
+///
+/// let (kelvin, provenance) =
+///     convert_with_provenance(0.0, TemperatureUnit::Celsius, TemperatureUnit::Kelvin).unwrap();
+/// assert_eq!(kelvin, 273.15);
+/// assert_eq!(provenance.formula, "y = x × 1 + 273.15");
+/// ```
+pub fn convert_with_provenance(
+    value: f64,
+    from: impl Into<Unit>,
+    to: impl Into<Unit>,
+) -> Result<(f64, Provenance), Error> {
+    let (from, to) = (from.into(), to.into());
+    let result = convert(value, from, to)?;
+
+    let offset = convert(0.0, from, to)?;
+    let factor = convert(1.0, from, to)? - offset;
+    let formula = if offset == 0.0 {
+        format!("y = x × {}", factor)
+    } else {
+        format!("y = x × {} + {}", factor, offset)
+    };
+
+    let provenance = Provenance {
+        input: Quantity::new(value, from),
+        output: Quantity::new(result, to),
+        factor,
+        offset,
+        formula,
+        timestamp: std::time::SystemTime::now(),
+    };
+    Ok((result, provenance))
+}
+
+/// Conversions within one category with every factor computed up front
+///
+/// Each pair of units is stored as the `factor` and `offset` of
+/// `y = x × factor + offset`, so a conversion is one table lookup and one
+/// multiply-add.
+#[derive(Debug, Clone)]
+pub struct PrecomputedConverter {
+    category: Category,
+    size: usize,
+    coefficients: Vec<(f64, f64)>,
+}
+
+impl PrecomputedConverter {
+    /// Precomputes the conversion between every pair of units in `category`
+    pub fn new(category: Category) -> Self {
+        let units: Vec<Unit> = Unit::all()
+            .into_iter()
+            .filter(|unit| unit.category() == category)
+            .collect();
+
+        let mut coefficients = vec![(1.0, 0.0); units.len() * units.len()];
+        for &from in &units {
+            for &to in &units {
+                let offset = to.from_base(from.to_base(0.0));
+                let factor = to.from_base(from.to_base(1.0)) - offset;
+                coefficients[Self::index(from) * units.len() + Self::index(to)] = (factor, offset);
+            }
+        }
+
+        PrecomputedConverter {
+            category,
+            size: units.len(),
+            coefficients,
+        }
+    }
+
+    /// Position of a unit within its category's `ALL` list
+    fn index(unit: Unit) -> usize {
+        match unit {
+            Unit::Length(unit) => unit as usize,
+            Unit::Temperature(unit) => unit as usize,
+            Unit::Pressure(unit) => unit as usize,
+            Unit::Mass(unit) => unit as usize,
+            Unit::Volume(unit) => unit as usize,
+        }
+    }
+
+    /// Converts a value using the precomputed table
+    ///
+    /// Converting a unit to itself returns `value` unchanged.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::CategoryMismatch`] if either unit is outside the
+    /// converter's category
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use conversion_lib::length::LengthUnit;
+    /// use conversion_lib::{Category, PrecomputedConverter};
+    ///
+    /// let converter = PrecomputedConverter::new(Category::Length);
+    /// let feet = converter.convert(1.0, LengthUnit::Miles, LengthUnit::Feet).unwrap();
+    /// assert!((feet - 5280.0).abs() < 1e-9);
+    /// ```
+    pub fn convert(
+        &self,
+        value: f64,
+        from: impl Into<Unit>,
+        to: impl Into<Unit>,
+    ) -> Result<f64, Error> {
+        let (from, to) = (from.into(), to.into());
+        check_same_category(from, self.category.base_unit())?;
+        check_same_category(to, self.category.base_unit())?;
+        if from == to {
+            return Ok(value);
+        }
+
+        let (factor, offset) = self.coefficients[Self::index(from) * self.size + Self::index(to)];
+        Ok(value * factor + offset)
+    }
+}
+
+/// Relative difference below which two results are treated as equal
+const EXPLAIN_TOLERANCE: f64 = 1e-9;
+
+/// Explains why someone else's conversion result differs from the correct one
+///
+/// Known mistakes are tried in turn: converting in the wrong direction,
+/// mixing up US and imperial gallons, and using US survey feet instead of
+/// international feet. The first that reproduces `their_result` is reported.
+///
+/// # Examples
+///
+/// ```
+/// use conversion_lib::explain_difference;
+///
+/// let explanation = explain_difference(10.0, "m", "ft", 3.048);
+/// assert!(explanation.contains("reverse"));
+/// ```
+pub fn explain_difference(value: f64, from: &str, to: &str, their_result: f64) -> String {
+    let (from_unit, to_unit) = match (from.parse::<Unit>(), to.parse::<Unit>()) {
+        (Ok(from_unit), Ok(to_unit)) => (from_unit, to_unit),
+        (Err(error), _) | (_, Err(error)) => return format!("cannot check: {}", error),
+    };
+    let expected = match convert(value, from_unit, to_unit) {
+        Ok(expected) => expected,
+        Err(error) => return format!("cannot check: {}", error),
+    };
+
+    let matches = |candidate: f64| {
+        (candidate - their_result).abs()
+            <= EXPLAIN_TOLERANCE * candidate.abs().max(f64::MIN_POSITIVE)
+    };
+    if matches(expected) {
+        return format!("{} {} is correct", their_result, to_unit);
+    }
+
+    let summary = format!("expected {} {}, got {}", expected, to_unit, their_result);
+    if convert(value, to_unit, from_unit).is_ok_and(matches) {
+        return format!(
+            "{}: the conversion appears to have been applied in reverse ({} to {})",
+            summary, to_unit, from_unit
+        );
+    }
+
+    let swap_gallon = |unit: Unit| match unit {
+        Unit::Volume(volume::VolumeUnit::UsGallons) => volume::VolumeUnit::ImperialGallons.into(),
+        Unit::Volume(volume::VolumeUnit::ImperialGallons) => volume::VolumeUnit::UsGallons.into(),
+        unit => unit,
+    };
+    let (from_swapped, to_swapped) = (swap_gallon(from_unit), swap_gallon(to_unit));
+    if (from_swapped, to_swapped) != (from_unit, to_unit)
+        && convert(value, from_swapped, to_swapped).is_ok_and(matches)
+    {
+        return format!(
+            "{}: US and imperial gallons appear to have been mixed up",
+            summary
+        );
+    }
+
+    let feet = Unit::Length(length::LengthUnit::Feet);
+    let survey_ratio = length::US_SURVEY_FOOT_M / length::LengthUnit::Feet.meters_per_unit();
+    let survey_result = match (from_unit == feet, to_unit == feet) {
+        (true, false) => Some(expected * survey_ratio),
+        (false, true) => Some(expected / survey_ratio),
+        _ => None,
+    };
+    if survey_result.is_some_and(matches) {
+        return format!(
+            "{}: US survey feet appear to have been used instead of international feet",
+            summary
+        );
+    }
+
+    format!(
+        "{}: off by {:.4}%, no known mistake explains the difference",
+        summary,
+        (their_result - expected) / expected * 100.0
+    )
+}
+
+/// Number of evenly spaced samples used by [`accuracy_report`]
+const ACCURACY_SAMPLES: u32 = 1000;
+
+/// Estimates the worst relative error of converting in `f32` instead of `f64`
+///
+/// Samples the category's [`typical_range`](Category::typical_range) and
+/// compares a single-precision `x × factor + offset` against the `f64`
+/// result. Samples whose exact result is zero are skipped. Affine
+/// temperature conversions can show large relative errors near the zero of
+/// the target scale, where the result itself is tiny.
+///
+/// # Errors
+///
+/// Returns [`Error::CategoryMismatch`] if the units belong to different categories
+///
+/// # Examples
+///
+/// ```
+/// use conversion_lib::accuracy_report;
+/// use conversion_lib::length::LengthUnit;
+///
+/// let error = accuracy_report((LengthUnit::Meters, LengthUnit::Feet)).unwrap();
+/// assert!(error < 1e-6);
+/// ```
+pub fn accuracy_report(unit_pair: (impl Into<Unit>, impl Into<Unit>)) -> Result<f64, Error> {
+    let (from, to) = (unit_pair.0.into(), unit_pair.1.into());
+    let offset = convert(0.0, from, to)?;
+    let factor = convert(1.0, from, to)? - offset;
+
+    let (min, max) = from.category().typical_range();
+    let (min, max) = (from.from_base(min), from.from_base(max));
+    let mut worst: f64 = 0.0;
+    for i in 0..=ACCURACY_SAMPLES {
+        let value = min + (max - min) * f64::from(i) / f64::from(ACCURACY_SAMPLES);
+        let exact = convert(value, from, to)?;
+        if exact == 0.0 {
+            continue;
+        }
+        let single = (value as f32) * (factor as f32) + (offset as f32);
+        worst = worst.max((f64::from(single) - exact).abs() / exact.abs());
+    }
+    Ok(worst)
+}
+
+/// Caches conversions of inputs rounded to a fixed quantum
+///
+/// Inputs are snapped to the nearest multiple of the quantum before
+/// converting, so jittery values such as from a UI slider share one cached
+/// result. Non-finite inputs, and inputs too far from zero to count their
+/// quantum steps in an `i64`, are converted directly and never cached.
+///
+/// # Examples
+///
+/// ```
+/// use conversion_lib::length::LengthUnit;
+/// use conversion_lib::QuantizedCache;
+///
+/// let mut cache = QuantizedCache::new(0.01);
+/// let a = cache.convert(1.0001, LengthUnit::Meters, LengthUnit::Feet).unwrap();
+/// let b = cache.convert(0.9999, LengthUnit::Meters, LengthUnit::Feet).unwrap();
+/// assert_eq!(a, b);
+/// assert_eq!(cache.len(), 1);
+/// ```
+#[derive(Debug, Clone)]
+pub struct QuantizedCache {
+    quantum: f64,
+    entries: HashMap<(i64, Unit, Unit), f64>,
+}
+
+impl QuantizedCache {
+    /// Creates an empty cache snapping inputs to multiples of `quantum`
+    ///
+    /// # Panics
+    ///
+    /// Panics if `quantum` is not positive and finite
+    pub fn new(quantum: f64) -> Self {
+        assert!(
+            quantum > 0.0 && quantum.is_finite(),
+            "quantum must be positive and finite"
+        );
+        QuantizedCache {
+            quantum,
+            entries: HashMap::new(),
+        }
+    }
+
+    /// Converts the input rounded to the nearest quantum, caching the result
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::CategoryMismatch`] if the units belong to different categories
+    pub fn convert(
+        &mut self,
+        value: f64,
+        from: impl Into<Unit>,
+        to: impl Into<Unit>,
+    ) -> Result<f64, Error> {
+        let (from, to) = (from.into(), to.into());
+        let steps = (value / self.quantum).round();
+        // i64::MAX rounds up to 2^63 as f64, which is already out of range
+        if !(steps >= i64::MIN as f64 && steps < i64::MAX as f64) {
+            return convert(value, from, to);
+        }
+
+        let key = (steps as i64, from, to);
+        if let Some(&cached) = self.entries.get(&key) {
+            return Ok(cached);
+        }
+        let result = convert(steps * self.quantum, from, to)?;
+        self.entries.insert(key, result);
+        Ok(result)
+    }
+
+    /// Returns the number of cached conversions
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns `true` if nothing has been cached yet
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
+/// Relative rounding error of each stage of a conversion step
+///
+/// A step goes from the input through the base unit to the output, and each
+/// of those stages rounds at most two floating-point operations (the scaling
+/// and, for the affine temperature scales, the offset) within half an ULP.
+const STAGE_ROUNDING_ERROR: f64 = f64::EPSILON;
+
+/// Returns how many base units one unit step of `unit` spans
+fn base_slope(unit: Unit) -> f64 {
+    (unit.to_base(1.0) - unit.to_base(0.0)).abs()
+}
+
+/// Converts a value along a chain of units while bounding rounding error
+///
+/// The value is converted from `path[0]` to `path[1]`, then to `path[2]`,
+/// and so on. The input is taken as exact; every step carries the absolute
+/// error so far through its scale factor and adds the rounding of its input,
+/// base-unit and output magnitudes. The estimate is that absolute error
+/// relative to the result, so affine steps that cancel an offset, such as
+/// ending near 0 °F, show a large relative error and an exact zero result
+/// reached through an offset an infinite one.
+///
+/// # Errors
+///
+/// * [`Error::InvalidInput`] if `path` has fewer than two units
+/// * [`Error::CategoryMismatch`] if a step mixes categories
+/// * [`Error::ErrorBudgetExceeded`] if the estimate exceeds `max_rel_error`
+///
+/// # Examples
+///
+/// ```
+/// use conversion_lib::length::LengthUnit;
+/// use conversion_lib::{convert_with_error_budget, Unit};
+///
+/// let path = [Unit::from(LengthUnit::Miles), LengthUnit::Feet.into(), LengthUnit::Meters.into()];
+/// let meters = convert_with_error_budget(1.0, &path, 1e-12).unwrap();
+/// assert!((meters - 1609.344).abs() < 1e-9);
+/// ```
+pub fn convert_with_error_budget(
+    value: f64,
+    path: &[Unit],
+    max_rel_error: f64,
+) -> Result<f64, Error> {
+    if path.len() < 2 {
+        return Err(Error::InvalidInput(
+            "a conversion path needs at least two units".to_string(),
+        ));
+    }
+
+    let mut result = value;
+    let mut absolute: f64 = 0.0;
+    for step in path.windows(2) {
+        let (from, to) = (step[0], step[1]);
+        let input = result;
+        result = convert(input, from, to)?;
+
+        let (into_base, out_of_base) = (base_slope(from), 1.0 / base_slope(to));
+        let magnitudes = input.abs() * into_base * out_of_base
+            + from.to_base(input).abs() * out_of_base
+            + result.abs();
+        absolute = absolute * into_base * out_of_base + STAGE_ROUNDING_ERROR * magnitudes;
+    }
+
+    let estimated = if absolute == 0.0 {
+        0.0
+    } else {
+        absolute / result.abs()
+    };
+
+    if estimated > max_rel_error {
+        return Err(Error::ErrorBudgetExceeded {
+            estimated,
+            budget: max_rel_error,
+        });
+    }
+    Ok(result)
+}
+
+/// Converts a value, substituting a default when the input or result is not finite
+///
+/// Keeps dashboards and charts working on bad data: NaN or infinite input,
+/// a non-finite result, or units that cannot be converted all yield `default`.
+///
+/// # Examples
+///
+/// ```
+/// use conversion_lib::length::LengthUnit;
+/// use conversion_lib::convert_or;
+///
+/// assert_eq!(convert_or(f64::NAN, LengthUnit::Meters, LengthUnit::Feet, 0.0), 0.0);
+/// assert_eq!(convert_or(1.0, LengthUnit::Kilometers, LengthUnit::Meters, 0.0), 1000.0);
+/// ```
+pub fn convert_or(value: f64, from: impl Into<Unit>, to: impl Into<Unit>, default: f64) -> f64 {
+    if !value.is_finite() {
+        return default;
+    }
+
+    match convert(value, from, to) {
+        Ok(result) if result.is_finite() => result,
+        _ => default,
+    }
+}
+
+/// Converts a value, passing a missing-data sentinel such as -9999 through unchanged
+///
+/// A value equal to `sentinel` is returned as is, so the marker survives the
+/// conversion and can still be recognised downstream. Any other value is
+/// converted as by [`convert`].
+///
+/// # Errors
+///
+/// Returns [`Error::CategoryMismatch`] if the units belong to different
+/// categories, even for a sentinel value
+///
+/// # Examples
+///
+/// ```
+/// use conversion_lib::convert_with_sentinel;
+/// use conversion_lib::temperature::TemperatureUnit;
+///
+/// let (c, f) = (TemperatureUnit::Celsius, TemperatureUnit::Fahrenheit);
+/// assert_eq!(convert_with_sentinel(-9999.0, c, f, -9999.0).unwrap(), -9999.0);
+/// assert!((convert_with_sentinel(100.0, c, f, -9999.0).unwrap() - 212.0).abs() < 1e-9);
+/// ```
+pub fn convert_with_sentinel(
+    value: f64,
+    from: impl Into<Unit>,
+    to: impl Into<Unit>,
+    sentinel: f64,
+) -> Result<f64, Error> {
+    let (from, to) = (from.into(), to.into());
+    check_same_category(from, to)?;
+    if value == sentinel {
+        return Ok(value);
+    }
+    convert(value, from, to)
+}
+
+/// Converts a value whose unit may be missing, falling back to a default unit
+///
+/// Keeps a pipeline running on rows without a unit while flagging which rows
+/// relied on the fallback.
+///
+/// # Arguments
+///
+/// * `value` - The value to convert
+/// * `from` - Unit symbol of `value`, if known
+/// * `to` - Unit symbol to convert to
+/// * `default_from` - Unit symbol assumed when `from` is `None`
+///
+/// # Returns
+///
+/// The converted value and whether the default unit was used
+///
+/// # Errors
+///
+/// Returns [`Error::UnknownUnit`] for unrecognised symbols and
+/// [`Error::CategoryMismatch`] if the units are from different categories
+///
+/// # Examples
+///
+/// ```
+/// use conversion_lib::convert_or_default;
+///
+/// let (meters, used_default) = convert_or_default(1.0, None, "m", "km").unwrap();
+/// assert_eq!(meters, 1000.0);
+/// assert!(used_default);
+/// ```
+pub fn convert_or_default(
+    value: f64,
+    from: Option<&str>,
+    to: &str,
+    default_from: &str,
+) -> Result<(f64, bool), Error> {
+    let used_default = from.is_none();
+    let from: Unit = from.unwrap_or(default_from).parse()?;
+    let to: Unit = to.parse()?;
+    Ok((convert(value, from, to)?, used_default))
+}
+
+/// Calculates the percentage change from one quantity to another
+///
+/// Both quantities are normalized to the base unit of their category, so
+/// comparing `1 km` with `500 m` works as expected.
+///
+/// # Errors
+///
+/// Returns [`Error::CategoryMismatch`] for quantities of different categories
+/// and [`Error::DivisionByZero`] when `from` is zero
+///
+/// # Examples
+///
+/// ```
+/// use conversion_lib::length::LengthUnit;
+/// use conversion_lib::{percent_change, Quantity};
+///
+/// let change = percent_change(
+///     Quantity::new(1.0, LengthUnit::Kilometers),
+///     Quantity::new(500.0, LengthUnit::Meters),
+/// )
+/// .unwrap();
+/// assert_eq!(change, -50.0);
+/// ```
+pub fn percent_change(from: Quantity, to: Quantity) -> Result<f64, Error> {
+    check_same_category(from.unit, to.unit)?;
+
+    let from_base = from.base_value();
+    if from_base == 0.0 {
+        return Err(Error::DivisionByZero);
+    }
+
+    Ok((to.base_value() - from_base) / from_base * 100.0)
+}
+
+static DEFAULT_PRECISION: AtomicUsize = AtomicUsize::new(2);
+
+/// Sets the number of decimal places used by formatting helpers without an
+/// explicit precision, such as [`render_template`]
+///
+/// The setting is process-wide and safe to change from any thread; it only
+/// affects formatted output, never the values used in conversions.
+///
+/// # Examples
+///
+/// ```
+/// use conversion_lib::{default_precision, set_default_precision};
+///
+/// set_default_precision(4);
+/// assert_eq!(default_precision(), 4);
+/// ```
+pub fn set_default_precision(n: usize) {
+    DEFAULT_PRECISION.store(n, Ordering::Relaxed);
+}
+
+/// Returns the default number of decimal places for formatted output (2 unless changed)
+pub fn default_precision() -> usize {
+    DEFAULT_PRECISION.load(Ordering::Relaxed)
+}
+
+/// Formats a value with its unit symbol and a thousands separator
+///
+/// The separator is inserted every three digits of the integer part; the
+/// fractional part is left as is.
+///
+/// # Arguments
+///
+/// * `value` - The value to format
+/// * `unit` - Unit whose symbol is appended
+/// * `precision` - Number of decimal places
+/// * `group_sep` - Separator between digit groups, e.g. `','`
+///
+/// # Examples
+///
+/// ```
+/// use conversion_lib::pressure::PressureUnit;
+/// use conversion_lib::format_grouped;
+///
+/// assert_eq!(format_grouped(101325.0, PressureUnit::Pascal, 0, ','), "101,325 Pa");
+/// ```
+pub fn format_grouped(
+    value: f64,
+    unit: impl Into<Unit>,
+    precision: usize,
+    group_sep: char,
+) -> String {
+    let formatted = format!("{:.*}", precision, value.abs());
+    let (integer, fraction) = match formatted.split_once('.') {
+        Some((integer, fraction)) => (integer, Some(fraction)),
+        None => (formatted.as_str(), None),
+    };
+
+    let mut grouped = String::new();
+    for (i, digit) in integer.chars().enumerate() {
+        if i > 0 && (integer.len() - i) % 3 == 0 {
+            grouped.push(group_sep);
+        }
+        grouped.push(digit);
+    }
+    if let Some(fraction) = fraction {
+        grouped.push('.');
+        grouped.push_str(fraction);
+    }
+
+    // Rounding may turn a tiny negative value into zero, which has no sign
+    let sign = if value < 0.0 && formatted.chars().any(|c| c.is_ascii_digit() && c != '0') {
+        "-"
+    } else {
+        ""
+    };
+
+    format!("{}{} {}", sign, grouped, unit.into())
+}
+
+/// Fills `{name:unit}` placeholders in a template with converted quantities
+///
+/// Each placeholder names a variable and the unit to show it in, e.g.
+/// `"Operating at {temp:°F}"`. The unit may be omitted (`{temp}`) to keep
+/// the variable's own unit. Values are shown with [`default_precision`]
+/// decimal places followed by the unit symbol.
+///
+/// # Errors
+///
+/// * [`Error::UnknownPlaceholder`] if a placeholder names a missing variable
+/// * [`Error::UnknownUnit`] or [`Error::CategoryMismatch`] for an invalid unit
+/// * [`Error::Parse`] if a `{` is not closed
+///
+/// # Examples
+///
+/// ```
+/// use std::collections::HashMap;
+/// use conversion_lib::pressure::PressureUnit;
+/// use conversion_lib::{render_template, Quantity};
+///
+/// let vars = HashMap::from([("p", Quantity::new(1.0, PressureUnit::Bar))]);
+/// let text = render_template("Line pressure {p:psi}", &vars).unwrap();
+/// assert_eq!(text, "Line pressure 14.50 psi");
+/// ```
+pub fn render_template(template: &str, vars: &HashMap<&str, Quantity>) -> Result<String, Error> {
+    let mut output = String::with_capacity(template.len());
+    let mut rest = template;
+
+    while let Some(start) = rest.find('{') {
+        output.push_str(&rest[..start]);
+        let end = rest[start..]
+            .find('}')
+            .map(|offset| start + offset)
+            .ok_or_else(|| Error::Parse(format!("unclosed placeholder in {:?}", template)))?;
+
+        let placeholder = &rest[start + 1..end];
+        let (name, unit) = match placeholder.split_once(':') {
+            Some((name, unit)) => (name.trim(), Some(unit.trim())),
+            None => (placeholder.trim(), None),
+        };
+        let quantity = vars
+            .get(name)
+            .ok_or_else(|| Error::UnknownPlaceholder(name.to_string()))?;
+        let unit = match unit {
+            Some(symbol) => symbol.parse()?,
+            None => quantity.unit,
+        };
+
+        let value = convert(quantity.value, quantity.unit, unit)?;
+        output.push_str(&format!("{:.*} {}", default_precision(), value, unit));
+        rest = &rest[end + 1..];
+    }
+
+    output.push_str(rest);
+    Ok(output)
+}
+
+/// Unit symbols [`normalize_text`] ignores because they read as ordinary words
+const AMBIGUOUS_UNIT_WORDS: [&str; 7] = ["in", "C", "F", "K", "g", "t", "l"];
+
+/// Rewrites every quantity found in free text into the units of `target_system`
+///
+/// A quantity is a number followed by a unit symbol or alias as a separate
+/// word, e.g. `"3 m"` or `"25 °C"`; trailing punctuation after the unit is
+/// kept. Converted values use [`default_precision`] decimal places. All other
+/// text, including whitespace, is left untouched, and so are quantities whose
+/// unit is already used in `target_system`.
+///
+/// Symbols that are also ordinary words or letters, such as `in`, `C` or `g`,
+/// are not treated as units; write `°C`, `inches` or `grams` instead.
+///
+/// # Examples
+///
+/// ```
+/// use conversion_lib::{normalize_text, Region};
+///
+/// let text = normalize_text("Set the oven to 200 °C.", Region::Imperial);
+/// assert_eq!(text, "Set the oven to 392.00 °F.");
+/// ```
+pub fn normalize_text(s: &str, target_system: Region) -> String {
+    let mut words = Vec::new();
+    let mut start = None;
+    for (i, c) in s.char_indices() {
+        match (c.is_whitespace(), start) {
+            (true, Some(begin)) => {
+                words.push((begin, i));
+                start = None;
+            }
+            (false, None) => start = Some(i),
+            _ => {}
+        }
+    }
+    if let Some(begin) = start {
+        words.push((begin, s.len()));
+    }
+
+    let mut output = String::with_capacity(s.len());
+    let mut copied = 0;
+    let mut i = 0;
+    while i + 1 < words.len() {
+        let (number_start, number_end) = words[i];
+        let (unit_start, unit_end) = words[i + 1];
+        let unit_word =
+            s[unit_start..unit_end].trim_end_matches([',', '.', ';', ':', '!', '?', ')']);
+
+        let quantity = s[number_start..number_end]
+            .parse::<f64>()
+            .ok()
+            .filter(|_| !AMBIGUOUS_UNIT_WORDS.contains(&unit_word))
+            .zip(parse_unit(unit_word).ok())
+            .filter(|&(_, unit)| unit.in_region(target_system) != unit);
+        if let Some((value, unit)) = quantity {
+            let target = unit.in_region(target_system);
+            let converted = target.from_base(unit.to_base(value));
+            output.push_str(&s[copied..number_start]);
+            output.push_str(&format!("{:.*} {}", default_precision(), converted, target));
+            copied = unit_start + unit_word.len();
+            i += 2;
+        } else {
+            i += 1;
+        }
+    }
+
+    output.push_str(&s[copied..]);
+    output
+}
+
+/// Evaluates a sum or difference of quantities such as `"2 m + 30 cm"`
+///
+/// Terms are `<value> <unit>` pairs separated by `+`, `-` or `−`, with
+/// whitespace around every token. Each term is converted to the first term's
+/// unit, which is also the unit of the result.
+///
+/// # Errors
+///
+/// Returns [`Error::Parse`] for a malformed expression, [`Error::UnknownUnit`]
+/// for an unrecognised unit, [`Error::CategoryMismatch`] when terms come from
+/// different categories and [`Error::InvalidOperationForScale`] for
+/// interval-scale units such as °C, where sums have no physical meaning
+///
+/// # Examples
+///
+/// ```
+/// use conversion_lib::eval_expression;
+/// use conversion_lib::length::LengthUnit;
+///
+/// let total = eval_expression("2 m + 30 cm").unwrap();
+/// assert_eq!(total.unit, LengthUnit::Meters.into());
+/// assert!((total.value - 2.3).abs() < 1e-12);
+/// ```
+pub fn eval_expression(s: &str) -> Result<Quantity, Error> {
+    let tokens: Vec<&str> = s.split_whitespace().collect();
+    let term = |value: &str, unit: &str| -> Result<Quantity, Error> {
+        let number = value
+            .parse::<f64>()
+            .map_err(|_| Error::Parse(format!("invalid number {:?}", value)))?;
+        let unit: Unit = unit.parse()?;
+        if scale_type(unit) == ScaleType::Interval {
+            return Err(Error::InvalidOperationForScale(unit));
+        }
+        Ok(Quantity::new(number, unit))
+    };
+
+    if tokens.len() < 2 || tokens.len() % 3 != 2 {
+        return Err(Error::Parse(format!(
+            "expected \"<value> <unit> [+|- <value> <unit>]...\", got {:?}",
+            s
+        )));
+    }
+    let mut total = term(tokens[0], tokens[1])?;
+    for chunk in tokens[2..].chunks(3) {
+        let sign = match chunk[0] {
+            "+" => 1.0,
+            "-" | "\u{2212}" => -1.0,
+            op => return Err(Error::Parse(format!("unsupported operator {:?}", op))),
+        };
+        let operand = term(chunk[1], chunk[2])?;
+        total.value += sign * convert(operand.value, operand.unit, total.unit)?;
+    }
+    Ok(total)
+}
+
+/// Digit grouping and decimal separator conventions
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NumberLocale {
+    /// `1,234.56`
+    Us,
+    /// `1.234,56`
+    European,
+    /// `1 234,56`, with a space or no-break space between groups
+    French,
+}
+
+impl NumberLocale {
+    /// Returns the accepted group separators and the decimal separator
+    fn separators(self) -> (&'static [char], char) {
+        match self {
+            NumberLocale::Us => (&[','], '.'),
+            NumberLocale::European => (&['.'], ','),
+            NumberLocale::French => (&[' ', '\u{a0}', '\u{202f}'], ','),
+        }
+    }
+}
+
+/// Parses a number written with locale-specific separators
+///
+/// Group separators are optional, but when present every group after the
+/// first must have exactly three digits, so `"1,23.4"` is rejected in the US
+/// locale rather than misread.
+///
+/// # Errors
+///
+/// Returns [`Error::Parse`] if the text is not a number in `locale`
+///
+/// # Examples
+///
+/// ```
+/// use conversion_lib::{parse_localized_number, NumberLocale};
+///
+/// assert_eq!(parse_localized_number("1.234,56", NumberLocale::European).unwrap(), 1234.56);
+/// assert_eq!(parse_localized_number("1,234.56", NumberLocale::Us).unwrap(), 1234.56);
+/// ```
+pub fn parse_localized_number(s: &str, locale: NumberLocale) -> Result<f64, Error> {
+    let invalid = || Error::Parse(format!("invalid number {:?} for {:?} locale", s, locale));
+    let (group_separators, decimal_separator) = locale.separators();
+
+    let trimmed = s.trim();
+    let (sign, unsigned) = match trimmed.strip_prefix('-') {
+        Some(rest) => ("-", rest),
+        None => ("", trimmed.strip_prefix('+').unwrap_or(trimmed)),
+    };
+    let (integer, fraction) = match unsigned.split_once(decimal_separator) {
+        Some((integer, fraction)) => (integer, Some(fraction)),
+        None => (unsigned, None),
+    };
+
+    let groups: Vec<&str> = integer.split(group_separators).collect();
+    let digits_only = |part: &str| part.chars().all(|c| c.is_ascii_digit());
+    let well_grouped = groups.len() == 1
+        || (!groups[0].is_empty()
+            && groups[0].len() <= 3
+            && groups[1..].iter().all(|group| group.len() == 3));
+    if !well_grouped || !groups.iter().all(|group| digits_only(group)) {
+        return Err(invalid());
+    }
+    let integer = groups.concat();
+
+    let fraction = fraction.unwrap_or("");
+    if !digits_only(fraction) || (integer.is_empty() && fraction.is_empty()) {
+        return Err(invalid());
+    }
+
+    format!("{}{}.{}", sign, integer, fraction)
+        .trim_end_matches('.')
+        .parse()
+        .map_err(|_| invalid())
+}
+
+/// Parses and converts one column of tab-separated data
+///
+/// Columns are numbered from zero and blank lines are ignored. When
+/// `has_header` is `true` the first line is skipped.
+///
+/// # Errors
+///
+/// * [`Error::CategoryMismatch`] if the units belong to different categories
+/// * [`Error::Parse`] if a line has no such column or the cell is not a
+///   number; the message names the one-based line number
+///
+/// # Examples
+///
+/// ```
+/// use conversion_lib::length::LengthUnit;
+/// use conversion_lib::parse_tsv_column;
+///
+/// let data = "id\tlength\n1\t1000\n2\t2500\n";
+/// let km = parse_tsv_column(data, 1, true, LengthUnit::Meters, LengthUnit::Kilometers).unwrap();
+/// assert_eq!(km, vec![1.0, 2.5]);
+/// ```
+pub fn parse_tsv_column(
+    data: &str,
+    column: usize,
+    has_header: bool,
+    from: impl Into<Unit>,
+    to: impl Into<Unit>,
+) -> Result<Vec<f64>, Error> {
+    let (from, to) = (from.into(), to.into());
+    check_same_category(from, to)?;
+
+    data.lines()
+        .enumerate()
+        .skip(usize::from(has_header))
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(index, line)| {
+            let line_number = index + 1;
+            let cell = line.split('\t').nth(column).ok_or_else(|| {
+                Error::Parse(format!("line {}: missing column {}", line_number, column))
+            })?;
+            let value: f64 = cell.trim().parse().map_err(|_| {
+                Error::Parse(format!("line {}: invalid number {:?}", line_number, cell))
+            })?;
+            Ok(to.from_base(from.to_base(value)))
+        })
+        .collect()
+}
+
+/// Parses comma-separated columns whose headers carry a unit suffix
+///
+/// Each header has the form `<name>_<unit>`, such as `length_m` or `temp_C`.
+/// Every column is converted to the unit used in `to_system` (see
+/// [`Unit::in_region`]) and relabeled with that unit's symbol. Blank lines
+/// are ignored.
+///
+/// # Errors
+///
+/// * [`Error::Parse`] if a header has no unit suffix, a row has the wrong
+///   number of cells, or a cell is not a number; row errors name the
+///   one-based line number
+/// * [`Error::UnknownUnit`] if a header suffix is not a known unit
+///
+/// # Examples
+///
+/// ```
+/// use conversion_lib::{parse_csv_with_unit_headers, Region};
+///
+/// let columns = parse_csv_with_unit_headers("mass_kg\n1000\n", Region::Metric).unwrap();
+/// assert_eq!(columns, vec![("mass_kg".to_string(), vec![1000.0])]);
+/// ```
+pub fn parse_csv_with_unit_headers(
+    data: &str,
+    to_system: Region,
+) -> Result<Vec<(String, Vec<f64>)>, Error> {
+    let mut lines = data
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty());
+    let Some((_, header)) = lines.next() else {
+        return Ok(Vec::new());
+    };
+
+    let mut columns = Vec::new();
+    for cell in header.split(',').map(str::trim) {
+        let (name, symbol) = cell
+            .rsplit_once('_')
+            .ok_or_else(|| Error::Parse(format!("column {:?} has no unit suffix", cell)))?;
+        let from: Unit = symbol.parse()?;
+        let to = from.in_region(to_system);
+        columns.push((name, from, to, Vec::new()));
+    }
+
+    for (index, line) in lines {
+        let line_number = index + 1;
+        let cells: Vec<&str> = line.split(',').collect();
+        if cells.len() != columns.len() {
+            return Err(Error::Parse(format!(
+                "line {}: expected {} cells, found {}",
+                line_number,
+                columns.len(),
+                cells.len()
+            )));
+        }
+        for ((_, from, to, values), cell) in columns.iter_mut().zip(cells) {
+            let value: f64 = cell.trim().parse().map_err(|_| {
+                Error::Parse(format!("line {}: invalid number {:?}", line_number, cell))
+            })?;
+            values.push(to.from_base(from.to_base(value)));
+        }
+    }
+
+    Ok(columns
+        .into_iter()
+        .map(|(name, _, to, values)| (format!("{}_{}", name, to), values))
+        .collect())
+}
+
+/// Conversion factors loaded from a `from,to,factor` table
+///
+/// Each row states that one `from` equals `factor` `to`. The reverse
+/// direction is implied, and conversions not listed directly are composed
+/// from a chain of rows.
+#[derive(Debug, Clone, Default)]
+pub struct FactorTable {
+    edges: HashMap<String, Vec<(String, f64)>>,
+}
+
+impl FactorTable {
+    /// Converts a value between two units named in the table
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::UnknownUnit`] if either unit does not appear in the table
+    /// * [`Error::NoConversionPath`] if no chain of rows links the units
+    pub fn convert(&self, value: f64, from: &str, to: &str) -> Result<f64, Error> {
+        for unit in [from, to] {
+            if !self.edges.contains_key(unit) {
+                return Err(Error::UnknownUnit(unit.to_string()));
+            }
+        }
+
+        // Breadth-first search so the shortest chain of factors is used
+        let mut factors = HashMap::from([(from, 1.0)]);
+        let mut queue = std::collections::VecDeque::from([from]);
+        while let Some(unit) = queue.pop_front() {
+            if unit == to {
+                return Ok(value * factors[unit]);
+            }
+            let factor = factors[unit];
+            for (next, step) in &self.edges[unit] {
+                if !factors.contains_key(next.as_str()) {
+                    factors.insert(next, factor * step);
+                    queue.push_back(next);
+                }
+            }
+        }
+
+        Err(Error::NoConversionPath {
+            from: from.to_string(),
+            to: to.to_string(),
+        })
+    }
+}
+
+/// Loads conversion factors from CSV rows of `from,to,factor`
+///
+/// An optional `from,to,factor` header and blank lines are skipped.
+///
+/// # Errors
+///
+/// Returns [`Error::Parse`] naming the one-based line number if a row does
+/// not have three cells or its factor is not a finite, non-zero number
+///
+/// # Examples
+///
+/// ```
+/// use conversion_lib::load_factor_table;
+///
+/// let table = load_factor_table("from,to,factor\nyd,ft,3\nft,in,12\n").unwrap();
+/// assert_eq!(table.convert(2.0, "yd", "in").unwrap(), 72.0);
+/// ```
+pub fn load_factor_table(csv: &str) -> Result<FactorTable, Error> {
+    let mut table = FactorTable::default();
+    for (index, line) in csv.lines().enumerate() {
+        let line_number = index + 1;
+        let cells: Vec<&str> = line.split(',').map(str::trim).collect();
+        if line.trim().is_empty() || (index == 0 && cells == ["from", "to", "factor"]) {
+            continue;
+        }
+
+        let [from, to, factor] = cells[..] else {
+            return Err(Error::Parse(format!(
+                "line {}: expected from,to,factor",
+                line_number
+            )));
+        };
+        let factor: f64 = factor
+            .parse()
+            .ok()
+            .filter(|factor: &f64| factor.is_finite() && *factor != 0.0)
+            .ok_or_else(|| {
+                Error::Parse(format!("line {}: invalid factor {:?}", line_number, factor))
+            })?;
+
+        table
+            .edges
+            .entry(from.to_string())
+            .or_default()
+            .push((to.to_string(), factor));
+        table
+            .edges
+            .entry(to.to_string())
+            .or_default()
+            .push((from.to_string(), 1.0 / factor));
+    }
+    Ok(table)
+}
+
+/// A non-fatal note about a suspicious conversion result
+#[derive(Debug, Clone, PartialEq)]
+pub enum Warning {
+    /// The value lies outside [`Category::typical_range`]
+    OutOfTypicalRange {
+        category: Category,
+        base_value: f64,
+        min: f64,
+        max: f64,
+    },
+    /// The conversion involves a [`deprecated`] unit
+    DeprecatedUnit(Unit),
+}
+
+impl fmt::Display for Warning {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Warning::OutOfTypicalRange {
+                category,
+                base_value,
+                min,
+                max,
+            } => write!(
+                f,
+                "{:?} value {} is outside the typical range {} to {}",
+                category, base_value, min, max
+            ),
+            Warning::DeprecatedUnit(unit) => write!(f, "{} is deprecated", unit),
+        }
+    }
+}
+
+/// Reports whether a unit is deprecated and kept only for reading old data
+///
+/// The US survey foot ([`length::LengthUnit::UsSurveyFeet`]) is the only
+/// deprecated unit; NIST withdrew it in favour of the international foot at
+/// the end of 2022.
+///
+/// # Examples
+///
+/// ```
+/// use conversion_lib::deprecated;
+/// use conversion_lib::length::LengthUnit;
+///
+/// assert!(deprecated(LengthUnit::UsSurveyFeet));
+/// assert!(!deprecated(LengthUnit::Feet));
+/// ```
+pub fn deprecated(unit: impl Into<Unit>) -> bool {
+    matches!(unit.into(), Unit::Length(length::LengthUnit::UsSurveyFeet))
+}
+
+/// Converts a value like [`convert`], with a [`Warning::DeprecatedUnit`] for
+/// each [`deprecated`] unit involved
+///
+/// # Errors
+///
+/// Returns [`Error::CategoryMismatch`] if the units belong to different categories
+///
+/// # Examples
+///
+/// ```
+/// use conversion_lib::convert_lenient;
+/// use conversion_lib::length::LengthUnit;
+///
+/// let (meters, warnings) = convert_lenient(1.0, LengthUnit::UsSurveyFeet, LengthUnit::Meters).unwrap();
+/// assert!((meters - 1200.0 / 3937.0).abs() < 1e-15);
+/// assert_eq!(warnings.len(), 1);
+/// ```
+pub fn convert_lenient(
+    value: f64,
+    from: impl Into<Unit>,
+    to: impl Into<Unit>,
+) -> Result<(f64, Vec<Warning>), Error> {
+    let (from, to) = (from.into(), to.into());
+    let result = convert(value, from, to)?;
+    let mut warnings = Vec::new();
+    for unit in [from, to] {
+        if deprecated(unit) && !warnings.contains(&Warning::DeprecatedUnit(unit)) {
+            warnings.push(Warning::DeprecatedUnit(unit));
+        }
+    }
+    Ok((result, warnings))
+}
+
+/// Converts a value like [`convert`], refusing [`deprecated`] units
+///
+/// # Errors
+///
+/// Returns [`Error::DeprecatedUnit`] if either unit is deprecated and
+/// [`Error::CategoryMismatch`] if the units belong to different categories
+pub fn convert_strict(
+    value: f64,
+    from: impl Into<Unit>,
+    to: impl Into<Unit>,
+) -> Result<f64, Error> {
+    let (from, to) = (from.into(), to.into());
+    if let Some(unit) = [from, to].into_iter().find(|&unit| deprecated(unit)) {
+        return Err(failed(Error::DeprecatedUnit(unit)));
+    }
+    convert(value, from, to)
+}
+
+/// Converts a value and flags results outside the typical range of the category
+///
+/// The conversion itself always succeeds for valid units; implausible values
+/// such as a 500 °C body temperature only produce a [`Warning`]. The ranges
+/// used are the documented defaults of [`Category::typical_range`].
+///
+/// # Arguments
+///
+/// * `value` - The value to convert
+/// * `from` - Unit of `value`
+/// * `to` - Unit to convert to
+/// * `category` - The category the data is expected to belong to
+///
+/// # Errors
+///
+/// Returns [`Error::CategoryMismatch`] if either unit is not in `category`
+///
+/// # Examples
+///
+/// ```
+/// use conversion_lib::temperature::TemperatureUnit;
+/// use conversion_lib::{convert_checked_plausible, Category};
+///
+/// let (fahrenheit, warnings) = convert_checked_plausible(
+///     37.0,
+///     TemperatureUnit::Celsius,
+///     TemperatureUnit::Fahrenheit,
+///     Category::Temperature,
+/// )
+/// .unwrap();
+/// assert!((fahrenheit - 98.6).abs() < 1e-9);
+/// assert!(warnings.is_empty());
+/// ```
+pub fn convert_checked_plausible(
+    value: f64,
+    from: impl Into<Unit>,
+    to: impl Into<Unit>,
+    category: Category,
+) -> Result<(f64, Vec<Warning>), Error> {
+    let (from, to) = (from.into(), to.into());
+    for unit in [from, to] {
+        if unit.category() != category {
+            return Err(Error::CategoryMismatch {
+                from: unit.category(),
+                to: category,
+            });
+        }
+    }
+
+    let base_value = from.to_base(value);
+    let (min, max) = category.typical_range();
+    let mut warnings = Vec::new();
+    if !(min..=max).contains(&base_value) {
+        warnings.push(Warning::OutOfTypicalRange {
+            category,
+            base_value,
+            min,
+            max,
+        });
+    }
+
+    Ok((to.from_base(base_value), warnings))
+}
+
+/// Checks that a quantity lies within an inclusive range
+///
+/// The value and both bounds may use different units of the same category;
+/// all three are normalized to the base unit before comparing.
+///
+/// # Errors
+///
+/// * [`Error::CategoryMismatch`] if the quantities are from different categories
+/// * [`Error::InvalidRange`] if `min` is greater than `max`
+/// * [`Error::OutOfRange`] if `value` is outside the range
+///
+/// # Examples
+///
+/// ```
+/// use conversion_lib::length::LengthUnit;
+/// use conversion_lib::{validate_in_range, Quantity};
+///
+/// let result = validate_in_range(
+///     Quantity::new(6.0, LengthUnit::Feet),
+///     Quantity::new(1.0, LengthUnit::Meters),
+///     Quantity::new(2.0, LengthUnit::Meters),
+/// );
+/// assert!(result.is_ok());
+/// ```
+pub fn validate_in_range(value: Quantity, min: Quantity, max: Quantity) -> Result<(), Error> {
+    check_same_category(value.unit, min.unit)?;
+    check_same_category(value.unit, max.unit)?;
+
+    let (min_base, max_base) = (min.base_value(), max.base_value());
+    if min_base > max_base {
+        return Err(Error::InvalidRange { min, max });
+    }
+    if !(min_base..=max_base).contains(&value.base_value()) {
+        return Err(Error::OutOfRange { value, min, max });
+    }
+
+    Ok(())
+}
+
+/// Checks a measurement against a nominal value ± tolerance
+///
+/// All three quantities may use different units of the same category. The
+/// tolerance is a difference, so for temperatures only the size of the degree
+/// matters: ±1 °F is ±5/9 K, not an absolute temperature.
+///
+/// # Errors
+///
+/// * [`Error::CategoryMismatch`] if the quantities are from different categories
+/// * [`Error::InvalidInput`] if the tolerance is negative
+///
+/// # Examples
+///
+/// ```
+/// use conversion_lib::length::LengthUnit;
+/// use conversion_lib::{within_tolerance, Quantity};
+///
+/// let ok = within_tolerance(
+///     Quantity::new(1001.0, LengthUnit::Millimeters),
+///     Quantity::new(1.0, LengthUnit::Meters),
+///     Quantity::new(0.1, LengthUnit::Inches),
+/// );
+/// assert_eq!(ok, Ok(true));
+/// ```
+pub fn within_tolerance(
+    measured: Quantity,
+    nominal: Quantity,
+    tolerance: Quantity,
+) -> Result<bool, Error> {
+    check_same_category(measured.unit, nominal.unit)?;
+    check_same_category(measured.unit, tolerance.unit)?;
+    if tolerance.value < 0.0 {
+        return Err(Error::InvalidInput(format!(
+            "tolerance {} must not be negative",
+            tolerance
+        )));
+    }
+
+    // Delisle runs backwards, so only the size of a degree matters
+    let tolerance_base = tolerance.value * base_slope(tolerance.unit);
+    Ok((measured.base_value() - nominal.base_value()).abs() <= tolerance_base)
+}
+
+fn check_same_category(a: Unit, b: Unit) -> Result<(), Error> {
+    if a.category() == b.category() {
+        Ok(())
+    } else {
+        Err(Error::CategoryMismatch {
+            from: a.category(),
+            to: b.category(),
+        })
+    }
+}
+
+/// Asserts that two quantities are equal within a tolerance
+///
+/// Both quantities are normalized to the base unit of their category and the
+/// tolerance is given in that base unit. On failure the quantities are shown
+/// in their original units, e.g. `1 m vs 3.2 ft`.
+///
+/// # Panics
+///
+/// Panics if the quantities differ by more than the tolerance or belong to
+/// different categories
+///
+/// # Examples
+///
+/// ```
+/// use conversion_lib::length::LengthUnit;
+/// use conversion_lib::{assert_quantity_eq, Quantity};
+///
+/// assert_quantity_eq!(
+///     Quantity::new(1.0, LengthUnit::Kilometers),
+///     Quantity::new(1000.0, LengthUnit::Meters),
+///     1e-9
+/// );
+/// ```
+#[macro_export]
+macro_rules! assert_quantity_eq {
+    ($left:expr, $right:expr, $tolerance:expr $(,)?) => {{
+        let left: $crate::Quantity = $left;
+        let right: $crate::Quantity = $right;
+        let tolerance: f64 = $tolerance;
+        assert_eq!(
+            left.unit.category(),
+            right.unit.category(),
+            "quantities {} and {} are from different categories",
+            left,
+            right
+        );
+        let difference = (left.base_value() - right.base_value()).abs();
+        if difference.is_nan() || difference > tolerance {
+            panic!(
+                "assertion `left ≈ right` failed: {} vs {} (difference {} exceeds tolerance {})",
+                left, right, difference, tolerance
+            );
+        }
+    }};
+}
+
+/// Builds a [`Quantity`] from a literal and a unit symbol, e.g. `qty!(100 m)`
+///
+/// The unit is checked when the macro expands, so a misspelt unit is a
+/// compile error rather than a runtime [`Error::UnknownUnit`]. Accepted
+/// symbols are the ASCII forms of the unit symbols: `um mm cm m km in ft yd
+/// mi`, `C F K De`, `Pa bar psi`, `g kg lb t ton` and `m3 L ft3 gal_us
+/// gal_imp`. The expansion is a plain struct literal, so it also works in
+/// `const` items.
+///
+/// # Examples
+///
+/// ```
+/// use conversion_lib::length::LengthUnit;
+/// use conversion_lib::qty;
+///
+/// let feet = qty!(100 m).to(LengthUnit::Feet).unwrap();
+/// assert!((feet.value - 328.0839895013123).abs() < 1e-9);
+/// assert_eq!(qty!(-40 C).to_string(), "-40 °C");
+/// ```
+///
+/// ```compile_fail
+/// use conversion_lib::qty;
+///
+/// let bad = qty!(100 foo);
+/// ```
+#[macro_export]
+macro_rules! qty {
+    ($value:literal $unit:tt) => {
+        $crate::Quantity {
+            value: $value as f64,
+            unit: $crate::qty!(@unit $unit),
+        }
+    };
+    (@unit um) => { $crate::Unit::Length($crate::length::LengthUnit::Micrometers) };
+    (@unit mm) => { $crate::Unit::Length($crate::length::LengthUnit::Millimeters) };
+    (@unit cm) => { $crate::Unit::Length($crate::length::LengthUnit::Centimeters) };
+    (@unit m) => { $crate::Unit::Length($crate::length::LengthUnit::Meters) };
+    (@unit km) => { $crate::Unit::Length($crate::length::LengthUnit::Kilometers) };
+    (@unit in) => { $crate::Unit::Length($crate::length::LengthUnit::Inches) };
+    (@unit ft) => { $crate::Unit::Length($crate::length::LengthUnit::Feet) };
+    (@unit yd) => { $crate::Unit::Length($crate::length::LengthUnit::Yards) };
+    (@unit mi) => { $crate::Unit::Length($crate::length::LengthUnit::Miles) };
+    (@unit C) => { $crate::Unit::Temperature($crate::temperature::TemperatureUnit::Celsius) };
+    (@unit F) => { $crate::Unit::Temperature($crate::temperature::TemperatureUnit::Fahrenheit) };
+    (@unit K) => { $crate::Unit::Temperature($crate::temperature::TemperatureUnit::Kelvin) };
+    (@unit De) => { $crate::Unit::Temperature($crate::temperature::TemperatureUnit::Delisle) };
+    (@unit Pa) => { $crate::Unit::Pressure($crate::pressure::PressureUnit::Pascal) };
+    (@unit bar) => { $crate::Unit::Pressure($crate::pressure::PressureUnit::Bar) };
+    (@unit psi) => { $crate::Unit::Pressure($crate::pressure::PressureUnit::Psi) };
+    (@unit g) => { $crate::Unit::Mass($crate::mass::MassUnit::Grams) };
+    (@unit kg) => { $crate::Unit::Mass($crate::mass::MassUnit::Kilograms) };
+    (@unit lb) => { $crate::Unit::Mass($crate::mass::MassUnit::Pounds) };
+    (@unit t) => { $crate::Unit::Mass($crate::mass::MassUnit::Tonnes) };
+    (@unit ton) => { $crate::Unit::Mass($crate::mass::MassUnit::Tons) };
+    (@unit m3) => { $crate::Unit::Volume($crate::volume::VolumeUnit::CubicMeters) };
+    (@unit L) => { $crate::Unit::Volume($crate::volume::VolumeUnit::Liters) };
+    (@unit ft3) => { $crate::Unit::Volume($crate::volume::VolumeUnit::CubicFeet) };
+    (@unit gal_us) => { $crate::Unit::Volume($crate::volume::VolumeUnit::UsGallons) };
+    (@unit gal_imp) => { $crate::Unit::Volume($crate::volume::VolumeUnit::ImperialGallons) };
+    (@unit $other:tt) => {
+        compile_error!(concat!("unknown unit `", stringify!($other), "` in qty!"))
+    };
+}
+
+/// Errors returned by the checked conversion functions
+#[derive(Debug, Clone, PartialEq)]
+pub enum Error {
+    /// The unit symbol is not recognised
+    UnknownUnit(String),
+    /// The operation mixes units from different categories
+    CategoryMismatch { from: Category, to: Category },
+    /// The operation would divide by zero
+    DivisionByZero,
+    /// The value lies outside the allowed range
+    OutOfRange {
+        value: Quantity,
+        min: Quantity,
+        max: Quantity,
+    },
+    /// The lower bound of a range is above its upper bound
+    InvalidRange { min: Quantity, max: Quantity },
+    /// The input text could not be parsed
+    Parse(String),
+    /// An argument is outside the values the operation accepts
+    InvalidInput(String),
+    /// A template placeholder names a variable that was not provided
+    UnknownPlaceholder(String),
+    /// The estimated accumulated error exceeds the allowed budget
+    ErrorBudgetExceeded { estimated: f64, budget: f64 },
+    /// The operation is not meaningful on the unit's measurement scale
+    InvalidOperationForScale(Unit),
+    /// No chain of known factors links the two units
+    NoConversionPath { from: String, to: String },
+    /// A ratio was taken of a temperature on an interval scale
+    RatioRequiresAbsoluteScale(Unit),
+    /// The unit is deprecated and strict conversion was requested
+    DeprecatedUnit(Unit),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::UnknownUnit(symbol) => write!(f, "unknown unit: {}", symbol),
+            Error::CategoryMismatch { from, to } => {
+                write!(f, "cannot combine {:?} with {:?}", from, to)
+            }
+            Error::DivisionByZero => write!(f, "division by zero"),
+            Error::OutOfRange { value, min, max } => {
+                write!(f, "{} is outside the range {} to {}", value, min, max)
+            }
+            Error::InvalidRange { min, max } => {
+                write!(f, "invalid range: {} is greater than {}", min, max)
+            }
+            Error::Parse(message) => write!(f, "parse error: {}", message),
+            Error::InvalidInput(message) => write!(f, "invalid input: {}", message),
+            Error::UnknownPlaceholder(name) => write!(f, "unknown placeholder: {}", name),
+            Error::ErrorBudgetExceeded { estimated, budget } => write!(
+                f,
+                "estimated relative error {:e} exceeds budget {:e}",
+                estimated, budget
+            ),
+            Error::InvalidOperationForScale(unit) => {
+                write!(
+                    f,
+                    "operation is not meaningful on the interval scale of {}",
+                    unit
+                )
+            }
+            Error::NoConversionPath { from, to } => {
+                write!(f, "no conversion from {} to {}", from, to)
+            }
+            Error::RatioRequiresAbsoluteScale(unit) => {
+                write!(
+                    f,
+                    "ratios require an absolute temperature scale, not {}",
+                    unit
+                )
+            }
+            Error::DeprecatedUnit(unit) => write!(f, "{} is deprecated", unit),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+/// Names of the [`Error`] variants, indexed by [`Error::index`]
+const ERROR_KINDS: [&str; 13] = [
+    "UnknownUnit",
+    "CategoryMismatch",
+    "DivisionByZero",
+    "OutOfRange",
+    "InvalidRange",
+    "Parse",
+    "InvalidInput",
+    "UnknownPlaceholder",
+    "ErrorBudgetExceeded",
+    "InvalidOperationForScale",
+    "NoConversionPath",
+    "RatioRequiresAbsoluteScale",
+    "DeprecatedUnit",
+];
+
+impl Error {
+    /// Returns the position of the variant in declaration order
+    ///
+    /// Used to pick the [`Metrics`] counter and its name in [`ERROR_KINDS`].
+    fn index(&self) -> usize {
+        match self {
+            Error::UnknownUnit(_) => 0,
+            Error::CategoryMismatch { .. } => 1,
+            Error::DivisionByZero => 2,
+            Error::OutOfRange { .. } => 3,
+            Error::InvalidRange { .. } => 4,
+            Error::Parse(_) => 5,
+            Error::InvalidInput(_) => 6,
+            Error::UnknownPlaceholder(_) => 7,
+            Error::ErrorBudgetExceeded { .. } => 8,
+            Error::InvalidOperationForScale(_) => 9,
+            Error::NoConversionPath { .. } => 10,
+            Error::RatioRequiresAbsoluteScale(_) => 11,
+            Error::DeprecatedUnit(_) => 12,
+        }
+    }
+}
+
+/// Failure counters for conversions, one per [`Error`] variant
+///
+/// The process-wide instance returned by [`metrics`] counts failures of
+/// [`convert`], [`convert_strict`], [`convert_any`] and parsing a [`Unit`],
+/// including calls made by other functions of this crate. Functions that only
+/// probe whether a word is a unit, such as [`normalize_text`], are not counted. Successful conversions are not counted,
+/// so the hot path stays free of atomics.
+#[derive(Debug)]
+pub struct Metrics {
+    counters: [AtomicU64; ERROR_KINDS.len()],
+}
+
+impl Metrics {
+    /// Creates a set of counters that are all zero
+    pub const fn new() -> Self {
+        Metrics {
+            counters: [const { AtomicU64::new(0) }; ERROR_KINDS.len()],
+        }
+    }
+
+    /// Counts one occurrence of an error
+    pub fn record(&self, error: &Error) {
+        self.counters[error.index()].fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Returns the current count for every error variant, keyed by variant name
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use conversion_lib::{Error, Metrics};
+    ///
+    /// let metrics = Metrics::new();
+    /// metrics.record(&Error::DivisionByZero);
+    /// assert_eq!(metrics.snapshot()["DivisionByZero"], 1);
+    /// assert_eq!(metrics.snapshot()["Parse"], 0);
+    /// ```
+    pub fn snapshot(&self) -> HashMap<&'static str, u64> {
+        ERROR_KINDS
+            .iter()
+            .zip(&self.counters)
+            .map(|(&kind, counter)| (kind, counter.load(Ordering::Relaxed)))
+            .collect()
+    }
+
+    /// Sets every counter back to zero
+    pub fn reset(&self) {
+        for counter in &self.counters {
+            counter.store(0, Ordering::Relaxed);
+        }
+    }
+}
+
+impl Default for Metrics {
+    fn default() -> Self {
+        Metrics::new()
+    }
+}
+
+static METRICS: Metrics = Metrics::new();
+
+/// Returns the process-wide conversion failure counters
+pub fn metrics() -> &'static Metrics {
+    &METRICS
+}
+
+fn failed(error: Error) -> Error {
+    METRICS.record(&error);
+    error
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Mutex;
+
+    /// Serializes tests that depend on the global default precision
+    static PRECISION_LOCK: Mutex<()> = Mutex::new(());
+
+    #[test]
+    fn test_length_conversions() {
+        // Test meters to feet
+        let result = length::meters_to_feet(1.0);
+        assert!((result - 3.28084).abs() < 0.0001);
+
+        // Test round trip conversion
+        let meters = 100.0;
+        let feet = length::meters_to_feet(meters);
+        let back_to_meters = length::feet_to_meters(feet);
+        assert!((meters - back_to_meters).abs() < 0.0001);
+
+        // The inch is defined as exactly 25.4 mm
+        assert_eq!(
+            convert(
+                25.4,
+                length::LengthUnit::Millimeters,
+                length::LengthUnit::Inches
+            ),
+            Ok(1.0)
+        );
+    }
+
+    #[test]
+    fn test_temperature_conversions() {
+        // Test freezing point
+        assert_eq!(temperature::celsius_to_fahrenheit(0.0), 32.0);
+        
+        // Test boiling point
+        assert_eq!(temperature::celsius_to_fahrenheit(100.0), 212.0);
+
+        // Test Kelvin
+        assert_eq!(temperature::celsius_to_kelvin(0.0), 273.15);
+    }
+
+    #[test]
+    fn test_pressure_conversions() {
+        let bar = 1.0;
+        let psi = pressure::bar_to_psi(bar);
+        let back_to_bar = pressure::psi_to_bar(psi);
+        assert!((bar - back_to_bar).abs() < 0.0001);
+    }
+
+    #[test]
+    fn test_mass_conversions() {
+        let kg = 100.0;
+        let pounds = mass::kg_to_pounds(kg);
+        let back_to_kg = mass::pounds_to_kg(pounds);
+        assert!((kg - back_to_kg).abs() < 0.0001);
+    }
+
+    #[test]
+    fn test_guess_length_unit() {
+        // Typical room height is clearly meters
+        assert_eq!(
+            length::guess_unit(2.5, Category::Length),
+            Some(length::LengthUnit::Meters)
+        );
+
+        // Typical building length is clearly feet
+        assert_eq!(
+            length::guess_unit(250.0, Category::Length),
+            Some(length::LengthUnit::Feet)
+        );
+
+        // Values in between are ambiguous
+        assert_eq!(length::guess_unit(40.0, Category::Length), None);
+
+        // Non-length context never guesses a length unit
+        assert_eq!(length::guess_unit(2.5, Category::Mass), None);
+    }
+
+    #[test]
+    fn test_hoop_stress() {
+        // 10 bar in a 1 m diameter vessel with a 10 mm wall
+        let stress = pressure::hoop_stress(pressure::bar_to_pascal(10.0), 0.5, 0.01).unwrap();
+        assert!((stress - 50_000_000.0).abs() < 0.0001);
+
+        // Doubling the wall thickness halves the stress
+        let thicker = pressure::hoop_stress(1_000_000.0, 0.5, 0.02).unwrap();
+        assert!((thicker - 25_000_000.0).abs() < 0.0001);
+    }
+
+    #[test]
+    fn test_hoop_stress_invalid_inputs() {
+        for (p, r, t) in [
+            (1_000_000.0, 0.5, 0.0),
+            (1_000_000.0, 0.5, -0.01),
+            (-1_000_000.0, 0.5, 0.01),
+            (1_000_000.0, -0.5, 0.01),
+            (f64::NAN, 0.5, 0.01),
+            (1_000_000.0, f64::INFINITY, 0.01),
+        ] {
+            assert!(matches!(
+                pressure::hoop_stress(p, r, t),
+                Err(Error::InvalidInput(_))
+            ));
+        }
+    }
+
+    #[test]
+    fn test_convert_all_length_map() {
+        let all = length::convert_all_map(1.0, length::LengthUnit::Meters);
+
+        // One entry per supported unit, except the deprecated survey foot
+        assert_eq!(all.len(), length::LengthUnit::ALL.len() - 1);
+        assert!(!all.contains_key("ft_us"));
+
+        assert!((all["ft"] - 3.28084).abs() < 0.0001);
+        assert!((all["km"] - 0.001).abs() < 0.0001);
+        assert_eq!(all["m"], 1.0);
+    }
+
+    #[test]
+    fn test_delisle_is_inverted() {
+        // Fixed points of the reversed scale
+        assert_eq!(temperature::celsius_to_delisle(100.0), 0.0);
+        assert_eq!(temperature::celsius_to_delisle(0.0), 150.0);
+
+        // Delisle increases as temperature decreases
+        assert!(temperature::celsius_to_delisle(-10.0) > temperature::celsius_to_delisle(10.0));
+
+        // Round trip returns the original Celsius
+        for celsius in [-40.0, 0.0, 37.0, 100.0, 250.0] {
+            let delisle = temperature::celsius_to_delisle(celsius);
+            let back = temperature::delisle_to_celsius(delisle);
+            assert!((celsius - back).abs() < 0.0001);
+        }
+    }
+
+    #[test]
+    fn test_flow_from_pressure_drop() {
+        // Water through a 0.01 m² orifice with Cd = 0.61 and 0.5 bar drop
+        let q = flow::from_pressure_drop(50_000.0, 0.01, 0.61, 1000.0).unwrap();
+        assert!((q - 0.061).abs() < 0.0001);
+
+        // No pressure drop means no flow
+        assert_eq!(flow::from_pressure_drop(0.0, 0.01, 0.61, 1000.0), Ok(0.0));
+    }
+
+    #[test]
+    fn test_flow_from_pressure_drop_invalid_inputs() {
+        for (dp, area, cd, rho) in [
+            (-1.0, 0.01, 0.61, 1000.0),
+            (50_000.0, -0.01, 0.61, 1000.0),
+            (50_000.0, 0.01, -0.61, 1000.0),
+            (50_000.0, 0.01, 0.61, 0.0),
+            (50_000.0, 0.01, 0.61, -1000.0),
+            (f64::NAN, 0.01, 0.61, 1000.0),
+            (f64::INFINITY, 0.01, 0.61, 1000.0),
+            (50_000.0, 0.01, 0.61, f64::NAN),
+        ] {
+            assert!(matches!(
+                flow::from_pressure_drop(dp, area, cd, rho),
+                Err(Error::InvalidInput(_))
+            ));
+        }
+    }
+
+    #[test]
+    fn test_localized_unit_names() {
+        assert_eq!(
+            length::unit_name(length::LengthUnit::Meters, Lang::En),
+            "meters"
+        );
+        assert_eq!(
+            length::unit_name(length::LengthUnit::Meters, Lang::De),
+            "Meter"
+        );
+
+        // Symbols are not localized
+        assert_eq!(length::LengthUnit::Meters.symbol(), "m");
+    }
+
+    #[test]
+    fn test_conversions_preserve_sign() {
+        // Length: negative values are directions
+        assert_eq!(length::meters_to_feet(-5.0), -length::meters_to_feet(5.0));
+        assert_eq!(length::km_to_miles(-5.0), -length::km_to_miles(5.0));
+        assert_eq!(
+            length::convert(-5.0, length::LengthUnit::Meters, length::LengthUnit::Feet),
+            -length::convert(5.0, length::LengthUnit::Meters, length::LengthUnit::Feet)
+        );
+
+        // Pressure and mass differences keep their sign
+        assert_eq!(pressure::bar_to_psi(-2.0), -pressure::bar_to_psi(2.0));
+        assert_eq!(mass::kg_to_pounds(-3.0), -mass::kg_to_pounds(3.0));
+
+        // Temperatures below zero are ordinary signed values
+        assert_eq!(temperature::celsius_to_fahrenheit(-40.0), -40.0);
+    }
+
+    #[test]
+    fn test_parse_unit_symbols() {
+        assert_eq!(
+            "m".parse::<Unit>(),
+            Ok(Unit::Length(length::LengthUnit::Meters))
+        );
+        assert_eq!(
+            "psi".parse::<Unit>(),
+            Ok(Unit::Pressure(pressure::PressureUnit::Psi))
+        );
+        assert_eq!(
+            "furlong".parse::<Unit>(),
+            Err(Error::UnknownUnit("furlong".to_string()))
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_quantity_serde_round_trip() {
+        let quantity: Quantity = serde_json::from_str(r#"{"value":100,"unit":"m"}"#).unwrap();
+        assert_eq!(quantity, Quantity::new(100.0, length::LengthUnit::Meters));
+
+        let json = serde_json::to_string(&quantity).unwrap();
+        assert_eq!(json, r#"{"value":100.0,"unit":"m"}"#);
+        assert_eq!(serde_json::from_str::<Quantity>(&json).unwrap(), quantity);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_quantity_serde_rejects_unknown_unit() {
+        let result = serde_json::from_str::<Quantity>(r#"{"value":100,"unit":"furlong"}"#);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_percent_change() {
+        // 1 m to 1.5 m is a 50% increase
+        let change = percent_change(
+            Quantity::new(1.0, length::LengthUnit::Meters),
+            Quantity::new(1.5, length::LengthUnit::Meters),
+        );
+        assert!((change.unwrap() - 50.0).abs() < 0.0001);
+
+        // Cross-category comparisons are rejected
+        let mismatch = percent_change(
+            Quantity::new(1.0, length::LengthUnit::Meters),
+            Quantity::new(1.0, mass::MassUnit::Kilograms),
+        );
+        assert_eq!(
+            mismatch,
+            Err(Error::CategoryMismatch {
+                from: Category::Length,
+                to: Category::Mass
+            })
+        );
+
+        // Zero starting value has no percentage change
+        let zero = percent_change(
+            Quantity::new(0.0, length::LengthUnit::Meters),
+            Quantity::new(1.0, length::LengthUnit::Meters),
+        );
+        assert_eq!(zero, Err(Error::DivisionByZero));
+    }
+
+    #[test]
+    fn test_weight_at_local_gravity() {
+        // 1 kg on the Moon
+        assert!((mass::weight(1.0, mass::MOON_GRAVITY) - 1.62).abs() < 0.0001);
+
+        // 1 kg on Earth
+        assert!((mass::weight(1.0, mass::EARTH_GRAVITY) - 9.80665).abs() < 0.0001);
+
+        // Mars sits between the two
+        let on_mars = mass::weight(1.0, mass::MARS_GRAVITY);
+        assert!(on_mars > mass::weight(1.0, mass::MOON_GRAVITY));
+        assert!(on_mars < mass::weight(1.0, mass::EARTH_GRAVITY));
+    }
+
+    #[test]
+    fn test_running_total() {
+        let mut total = RunningTotal::new(Category::Length);
+        total
+            .add(Quantity::new(1.0, length::LengthUnit::Meters))
+            .unwrap();
+        total
+            .add(Quantity::new(2.0, length::LengthUnit::Feet))
+            .unwrap();
+        total
+            .add(Quantity::new(6.0, length::LengthUnit::Inches))
+            .unwrap();
+
+        // 1 m + 2 ft + 6 in = 5.78084 ft
+        assert!((total.total(length::LengthUnit::Feet) - 5.78084).abs() < 0.0001);
+
+        // Other categories are rejected and leave the total untouched
+        assert!(total
+            .add(Quantity::new(1.0, mass::MassUnit::Kilograms))
+            .is_err());
+        assert!((total.total(length::LengthUnit::Feet) - 5.78084).abs() < 0.0001);
+    }
+
+    #[test]
+    fn test_datarate_conversions() {
+        // 100 Mbps link moves 12.5 MB/s
+        assert_eq!(datarate::mbps_to_mbytes_per_s(100.0), 12.5);
+
+        // Round trips
+        let mbps = 42.0;
+        let back = datarate::mbytes_per_s_to_mbps(datarate::mbps_to_mbytes_per_s(mbps));
+        assert!((mbps - back).abs() < 0.0001);
+
+        let bps = 56_000.0;
+        assert!((bps - datarate::kbps_to_bps(datarate::bps_to_kbps(bps))).abs() < 0.0001);
+
+        let kbps = 512.0;
+        let back = datarate::kbytes_per_s_to_kbps(datarate::kbps_to_kbytes_per_s(kbps));
+        assert!((kbps - back).abs() < 0.0001);
+        assert_eq!(datarate::mbps_to_kbps(datarate::kbps_to_mbps(kbps)), kbps);
+    }
+
+    #[test]
+    fn test_convert_checked_plausible() {
+        use temperature::TemperatureUnit;
+
+        // Normal body temperature converts without warnings
+        let (fahrenheit, warnings) = convert_checked_plausible(
+            37.0,
+            TemperatureUnit::Celsius,
+            TemperatureUnit::Fahrenheit,
+            Category::Temperature,
+        )
+        .unwrap();
+        assert!((fahrenheit - 98.6).abs() < 0.0001);
+        assert!(warnings.is_empty());
+
+        // 500 °C still converts but is flagged
+        let (fahrenheit, warnings) = convert_checked_plausible(
+            500.0,
+            TemperatureUnit::Celsius,
+            TemperatureUnit::Fahrenheit,
+            Category::Temperature,
+        )
+        .unwrap();
+        assert!((fahrenheit - 932.0).abs() < 0.0001);
+        assert_eq!(warnings.len(), 1);
+        assert!(matches!(warnings[0], Warning::OutOfTypicalRange { .. }));
+    }
+
+    #[test]
+    fn test_linked_pair() {
+        use length::LengthUnit;
+
+        let mut pair = LinkedPair::new(LengthUnit::Meters, LengthUnit::Feet).unwrap();
+
+        // Editing meters updates feet
+        pair.set_from(LengthUnit::Meters, 10.0).unwrap();
+        assert!((pair.get(LengthUnit::Feet) - 32.8084).abs() < 0.0001);
+
+        // Editing feet updates meters
+        pair.set_from(LengthUnit::Feet, 100.0).unwrap();
+        assert!((pair.get(LengthUnit::Meters) - 30.48).abs() < 0.0001);
+
+        let (meters, feet) = pair.values();
+        assert!((meters - 30.48).abs() < 0.0001);
+        assert!((feet - 100.0).abs() < 0.0001);
+
+        // Units must share a category
+        assert!(pair.set_from(mass::MassUnit::Kilograms, 1.0).is_err());
+        assert!(LinkedPair::new(LengthUnit::Meters, mass::MassUnit::Kilograms).is_err());
+    }
+
+    #[test]
+    fn test_format_grouped() {
+        use pressure::PressureUnit;
+
+        assert_eq!(
+            format_grouped(101325.0, PressureUnit::Pascal, 0, ','),
+            "101,325 Pa"
+        );
+        assert_eq!(
+            format_grouped(1234567.891, PressureUnit::Pascal, 2, ','),
+            "1,234,567.89 Pa"
+        );
+
+        // Values under 1000 are unchanged
+        assert_eq!(
+            format_grouped(999.5, PressureUnit::Bar, 1, ','),
+            "999.5 bar"
+        );
+
+        // Negatives and other separators
+        assert_eq!(
+            format_grouped(-2500000.0, PressureUnit::Pascal, 0, ' '),
+            "-2 500 000 Pa"
+        );
+        assert_eq!(format_grouped(-0.001, PressureUnit::Pascal, 0, ','), "0 Pa");
+    }
+
+    #[test]
+    fn test_unit_aliases() {
+        // All spellings of meter resolve to the same unit
+        for spelling in ["m", "meter", "meters", "metre"] {
+            assert_eq!(
+                spelling.parse::<Unit>(),
+                Ok(Unit::Length(length::LengthUnit::Meters))
+            );
+        }
+
+        // No symbol or alias is claimed by two different units
+        let mut seen = std::collections::HashMap::new();
+        for unit in Unit::all() {
+            for name in std::iter::once(unit.symbol()).chain(unit.aliases().iter().copied()) {
+                if let Some(other) = seen.insert(name, unit) {
+                    panic!("{:?} is used by both {:?} and {:?}", name, other, unit);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_gas_reference_conditions() {
+        // Standard volume is about 5.5% larger than normal volume
+        let sm3 = volume::nm3_to_sm3(100.0);
+        assert!((sm3 - 105.49).abs() < 0.01);
+
+        // Round trips
+        let nm3 = 2500.0;
+        assert!((nm3 - volume::sm3_to_nm3(volume::nm3_to_sm3(nm3))).abs() < 0.0001);
+        let sm3 = 2500.0;
+        assert!((sm3 - volume::nm3_to_sm3(volume::sm3_to_nm3(sm3))).abs() < 0.0001);
+    }
+
+    #[test]
+    fn test_trapezoidal_integrate() {
+        // Constant 10 W for 60 seconds is 600 J
+        let samples = [10.0; 61];
+        assert!((util::trapezoidal_integrate(&samples, 1.0) - 600.0).abs() < 0.0001);
+
+        // Same energy sampled less often
+        assert!((util::trapezoidal_integrate(&[10.0, 10.0, 10.0], 30.0) - 600.0).abs() < 0.0001);
+    }
+
+    #[test]
+    #[should_panic(expected = "at least two samples are required")]
+    fn test_trapezoidal_integrate_single_sample() {
+        util::trapezoidal_integrate(&[10.0], 1.0);
+    }
+
+    #[test]
+    fn test_assert_quantity_eq_passes() {
+        assert_quantity_eq!(
+            Quantity::new(1.0, length::LengthUnit::Feet),
+            Quantity::new(12.0, length::LengthUnit::Inches),
+            0.0001
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "1 m vs 3.2 ft")]
+    fn test_assert_quantity_eq_fails() {
+        assert_quantity_eq!(
+            Quantity::new(1.0, length::LengthUnit::Meters),
+            Quantity::new(3.2, length::LengthUnit::Feet),
+            0.0001
+        );
+    }
+
+    #[test]
+    fn test_validate_in_range() {
+        use length::LengthUnit;
+
+        let min = Quantity::new(1.0, LengthUnit::Meters);
+        let max = Quantity::new(10.0, LengthUnit::Feet);
+
+        // 5 ft is between 1 m and 10 ft
+        assert!(validate_in_range(Quantity::new(5.0, LengthUnit::Feet), min, max).is_ok());
+
+        // 4 m is above 10 ft (3.048 m)
+        let value = Quantity::new(4.0, LengthUnit::Meters);
+        assert_eq!(
+            validate_in_range(value, min, max),
+            Err(Error::OutOfRange { value, min, max })
+        );
+
+        // Inverted range
+        assert_eq!(
+            validate_in_range(value, max, min),
+            Err(Error::InvalidRange { min: max, max: min })
+        );
+
+        // Cross-category
+        let kg = Quantity::new(1.0, mass::MassUnit::Kilograms);
+        assert!(matches!(
+            validate_in_range(kg, min, max),
+            Err(Error::CategoryMismatch { .. })
+        ));
+    }
+
+    #[test]
+    fn test_nearest_fastener() {
+        assert_eq!(length::nearest_fastener(0.00635), "1/4\"");
+        assert_eq!(length::nearest_fastener(0.0095), "3/8\"");
+
+        // Far outside the table snaps to the ends
+        assert_eq!(length::nearest_fastener(0.0001), "1/8\"");
+        assert_eq!(length::nearest_fastener(0.1), "1\"");
+    }
+
+    #[cfg(feature = "num-rational")]
+    #[test]
+    fn test_length_convert_exact_large_value() {
+        use length::LengthUnit;
+        use num_bigint::BigInt;
+        use num_rational::BigRational;
+
+        // The unreduced products overflow i64, the reduced result does not
+        let miles = BigRational::new(1_000_000_000_000_000_i64.into(), 3.into());
+        let feet = length::convert_exact(&miles, LengthUnit::Miles, LengthUnit::Feet);
+        assert_eq!(
+            feet,
+            BigRational::from_integer(1_760_000_000_000_000_000_i64.into())
+        );
+        assert!(1_000_000_000_000_000_i64
+            .checked_mul(201_168 * 1250)
+            .is_none());
+
+        let back = length::convert_exact(&feet, LengthUnit::Feet, LengthUnit::Miles);
+        assert_eq!(back, miles);
+        assert_eq!(
+            length::convert_exact(
+                &BigRational::from_integer((-1).into()),
+                LengthUnit::Feet,
+                LengthUnit::Meters
+            ),
+            BigRational::new((-381).into(), 1250.into())
+        );
+
+        // Results far beyond i64 stay exact
+        let huge = BigRational::from_integer(BigInt::from(i64::MAX) * 1000);
+        let inches = length::convert_exact(&huge, LengthUnit::Kilometers, LengthUnit::Inches);
+        assert_eq!(
+            inches * BigRational::from_integer(254.into()),
+            &huge * BigRational::from_integer(10_000_000.into())
+        );
+        assert_eq!(
+            length::convert_exact(&huge, LengthUnit::Kilometers, LengthUnit::Meters),
+            huge * BigRational::from_integer(1000.into())
+        );
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn test_meters_to_feet_par_matches_serial() {
+        let input: Vec<f64> = (0..1_000_000).map(|i| i as f64 * 0.001).collect();
+        let serial: Vec<f64> = input.iter().map(|&m| length::meters_to_feet(m)).collect();
+        assert_eq!(length::meters_to_feet_par(&input), serial);
+    }
+
+    #[test]
+    fn test_guess_temperature_scale() {
+        use temperature::TemperatureUnit;
+
+        assert_eq!(
+            temperature::guess_scale(300.0),
+            Some(TemperatureUnit::Kelvin)
+        );
+        assert_eq!(
+            temperature::guess_scale(20.0),
+            Some(TemperatureUnit::Celsius)
+        );
+        assert_eq!(
+            temperature::guess_scale(77.0),
+            Some(TemperatureUnit::Fahrenheit)
+        );
+
+        // Overlapping and out-of-range readings are ambiguous
+        assert_eq!(temperature::guess_scale(40.0), None);
+        assert_eq!(temperature::guess_scale(1500.0), None);
+    }
+
+    #[test]
+    fn test_data_byte_modes() {
+        use data::{ByteMode, DataUnit};
+
+        // 1 KiB is 1024 bytes, i.e. 1.024 decimal KB
+        let kb = data::convert(
+            1.0,
+            DataUnit::Kibibytes,
+            DataUnit::Kilobytes,
+            ByteMode::Decimal,
+        );
+        assert!((kb - 1.024).abs() < 0.0001);
+
+        // 1 decimal MB is about 0.9537 MiB
+        let mib = data::convert(
+            1.0,
+            DataUnit::Megabytes,
+            DataUnit::Mebibytes,
+            ByteMode::Decimal,
+        );
+        assert!((mib - 0.953674).abs() < 0.0001);
+
+        // In binary mode MB and MiB are the same size
+        let mib = data::convert(
+            1.0,
+            DataUnit::Megabytes,
+            DataUnit::Mebibytes,
+            ByteMode::Binary,
+        );
+        assert_eq!(mib, 1.0);
+
+        assert!(DataUnit::Kibibytes.is_binary());
+        assert!(!DataUnit::Kilobytes.is_binary());
+    }
+
+    #[test]
+    fn test_constant_lookup() {
+        assert_eq!(constants::lookup("g"), Some((9.80665, "m/s²")));
+        assert_eq!(constants::lookup("c"), Some((299_792_458.0, "m/s")));
+        assert_eq!(constants::lookup("unobtainium"), None);
+    }
+
+    #[test]
+    fn test_convert_interval_encloses_exact_value() {
+        use length::LengthUnit;
+
+        // Conversions with exactly known results
+        let cases = [
+            (5.0, LengthUnit::Miles, LengthUnit::Feet, 26400.0),
+            (1.0, LengthUnit::Feet, LengthUnit::Inches, 12.0),
+            (3.0, LengthUnit::Feet, LengthUnit::Meters, 0.9144),
+            (-2.0, LengthUnit::Kilometers, LengthUnit::Meters, -2000.0),
+            (1.0, LengthUnit::Meters, LengthUnit::Feet, 1.0 / 0.3048),
+        ];
+
+        for (value, from, to, exact) in cases {
+            let (lo, hi) = length::convert_interval(value, from, to);
+            assert!(
+                lo <= exact && exact <= hi,
+                "{} not in [{}, {}]",
+                exact,
+                lo,
+                hi
+            );
+
+            // The interval stays tight
+            assert!((hi - lo) <= exact.abs() * 1e-14);
+        }
+    }
+
+    #[test]
+    fn test_parse_facility_report() {
+        let spec = demo::FacilitySpec {
+            capacity_tonnes_per_day: 500.0,
+            operating_temp_c: 55.0,
+            operating_pressure_bar: 1.5,
+        };
+
+        let parsed = demo::parse_report(&spec.report()).unwrap();
+        assert!((parsed.capacity_tonnes_per_day - 500.0).abs() < 0.0001);
+        assert!((parsed.operating_temp_c - 55.0).abs() < 0.0001);
+        assert!((parsed.operating_pressure_bar - 1.5).abs() < 0.0001);
+
+        // Missing fields are reported
+        assert!(matches!(
+            demo::parse_report("AD Facility Specifications:\n  Capacity: 500 tonnes/day"),
+            Err(Error::Parse(_))
+        ));
+    }
+
+    #[test]
+    fn test_powered_factors_match_length() {
+        use length::LengthUnit;
+
+        let m_to_ft = length::convert(1.0, LengthUnit::Meters, LengthUnit::Feet);
+
+        // Area and volume factors are exact powers of the length factor
+        assert_eq!(area::square_meters_to_square_feet(1.0), m_to_ft * m_to_ft);
+        assert_eq!(
+            volume::cubic_meters_to_cubic_feet(1.0),
+            m_to_ft * m_to_ft * m_to_ft
+        );
+        assert_eq!(util::powered_factor(m_to_ft, 0), 1.0);
+
+        // Round trips
+        let m2 = 250.0;
+        let back = area::square_feet_to_square_meters(area::square_meters_to_square_feet(m2));
+        assert!((m2 - back).abs() < 0.0001);
+        let m3 = 12.5;
+        let back = volume::cubic_feet_to_cubic_meters(volume::cubic_meters_to_cubic_feet(m3));
+        assert!((m3 - back).abs() < 0.0001);
+    }
+
+    #[test]
+    fn test_convert_or_default() {
+        // Unit present: used as is
+        let (feet, used_default) = convert_or_default(3.0, Some("m"), "ft", "in").unwrap();
+        assert!((feet - 9.84252).abs() < 0.0001);
+        assert!(!used_default);
+
+        // Unit missing: falls back and flags it
+        let (feet, used_default) = convert_or_default(24.0, None, "ft", "in").unwrap();
+        assert!((feet - 2.0).abs() < 0.0001);
+        assert!(used_default);
+
+        // Unknown units are still errors
+        assert!(convert_or_default(1.0, Some("cubit"), "ft", "in").is_err());
+    }
+
+    #[test]
+    fn test_water_mass() {
+        // 1 L at 20 °C
+        assert!((mass::water_mass(1.0) - 0.9982).abs() < 0.0001);
+        assert!((mass::water_mass_at(1.0, 20.0).unwrap() - 0.9982).abs() < 0.0001);
+
+        // Water is densest around 4 °C
+        assert!((mass::water_mass_at(1.0, 4.0).unwrap() - 1.0).abs() < 0.0001);
+
+        // Interpolated between table entries
+        let at_35 = mass::water_mass_at(1.0, 35.0).unwrap();
+        assert!(at_35 < mass::water_mass_at(1.0, 30.0).unwrap());
+        assert!(at_35 > mass::water_mass_at(1.0, 40.0).unwrap());
+
+        // Both ends of the table are included, anything beyond is rejected
+        assert!(mass::water_mass_at(1.0, 0.0).is_ok());
+        assert!(mass::water_mass_at(1.0, 100.0).is_ok());
+        for temp_c in [-0.1, 100.1, f64::NAN] {
+            assert!(matches!(
+                mass::water_mass_at(1.0, temp_c),
+                Err(Error::InvalidInput(_))
+            ));
+        }
+    }
+
+    #[test]
+    fn test_histogram() {
+        // Temperatures converted to Fahrenheit (32 – 100.4 °F), binned by 10 °F from 30 °F
+        let fahrenheit: Vec<f64> = [0.0, 5.0, 12.0, 21.0, 22.0, 38.0]
+            .iter()
+            .map(|&c| temperature::celsius_to_fahrenheit(c))
+            .collect();
+        let counts = util::histogram(&fahrenheit, 10.0, 30.0).unwrap();
+        assert_eq!(counts, vec![1, 1, 1, 1, 1, 0, 0, 1]);
+
+        // Values below the minimum are ignored
+        assert_eq!(util::histogram(&[-5.0, 0.5], 1.0, 0.0).unwrap(), vec![1]);
+        assert!(util::histogram(&[], 1.0, 0.0).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_histogram_rejects_unbounded_bins() {
+        // Infinities are skipped like NaN
+        let counts = util::histogram(&[0.5, f64::INFINITY, f64::NEG_INFINITY, f64::NAN], 1.0, 0.0);
+        assert_eq!(counts.unwrap(), vec![1]);
+
+        // A huge finite value would need an enormous allocation
+        assert!(matches!(
+            util::histogram(&[0.5, 1e300], 1.0, 0.0),
+            Err(Error::InvalidInput(_))
+        ));
+        assert!(matches!(
+            util::histogram(&[0.5], 0.0, 0.0),
+            Err(Error::InvalidInput(_))
+        ));
+    }
+
+    #[test]
+    fn test_every_unit_converts_to_and_from_base() {
+        for category in Category::ALL {
+            let base = category.base_unit();
+            assert_eq!(base.category(), category);
+            assert!(Unit::all().iter().any(|unit| unit.category() == category));
+        }
+
+        // For every registered unit, a path exists both to and from the base
+        for unit in Unit::all() {
+            let base = unit.category().base_unit();
+            for value in [-100.0, 0.0, 1.0, 273.15, 1.0e6] {
+                let at_base = convert(value, unit, base).unwrap();
+                let back = convert(at_base, base, unit).unwrap();
+                assert!(at_base.is_finite(), "{} has no path to {}", unit, base);
+                assert!(
+                    (back - value).abs() <= 1e-9 * value.abs().max(1.0),
+                    "{} does not round-trip through {}: {} -> {}",
+                    unit,
+                    base,
+                    value,
+                    back
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_convert_verbose_shows_base_value() {
+        use length::LengthUnit;
+
+        let trace = length::convert_verbose(3.0, LengthUnit::Feet, LengthUnit::Kilometers);
+        assert!(trace.contains("0.9144 m"));
+        assert!(trace.ends_with("0.0009144 km"));
+
+        let trace = length::convert_verbose(2.0, LengthUnit::Miles, LengthUnit::Feet);
+        assert_eq!(trace, "2 mi = 3218.688 m → 10560 ft");
+    }
+
+    #[test]
+    fn test_subtract_saturating_mass() {
+        use mass::MassUnit;
+
+        // 10 kg - 5 lb = 7.732 kg
+        let left = mass::subtract_saturating(
+            Quantity::new(10.0, MassUnit::Kilograms),
+            Quantity::new(5.0, MassUnit::Pounds),
+        )
+        .unwrap();
+        assert_eq!(left.unit, Unit::Mass(MassUnit::Kilograms));
+        assert!((left.value - 7.732).abs() < 0.001);
+
+        // Using more than is available clamps to zero
+        let left = mass::subtract_saturating(
+            Quantity::new(1.0, MassUnit::Pounds),
+            Quantity::new(1.0, MassUnit::Kilograms),
+        )
+        .unwrap();
+        assert_eq!(left, Quantity::new(0.0, MassUnit::Pounds));
+
+        // Other categories are rejected
+        let length = Quantity::new(1.0, length::LengthUnit::Meters);
+        assert!(mass::subtract_saturating(left, length).is_err());
+    }
+
+    #[test]
+    fn test_convert_temperature_grid() {
+        use temperature::TemperatureUnit;
+
+        let celsius = vec![
+            vec![0.0, 10.0, 20.0],
+            vec![30.0, 40.0, 50.0],
+            vec![60.0, 70.0, 100.0],
+        ];
+        let fahrenheit = temperature::convert_grid(
+            &celsius,
+            TemperatureUnit::Celsius,
+            TemperatureUnit::Fahrenheit,
+        );
+
+        assert_eq!(fahrenheit.len(), 3);
+        assert!(fahrenheit.iter().all(|row| row.len() == 3));
+        assert!((fahrenheit[0][0] - 32.0).abs() < 0.0001);
+        assert!((fahrenheit[1][1] - 104.0).abs() < 0.0001);
+        assert!((fahrenheit[2][2] - 212.0).abs() < 0.0001);
+
+        // Ragged rows keep their own lengths
+        let ragged = vec![vec![0.0], vec![], vec![100.0, 37.0]];
+        let converted =
+            temperature::convert_grid(&ragged, TemperatureUnit::Celsius, TemperatureUnit::Kelvin);
+        let lengths: Vec<usize> = converted.iter().map(Vec::len).collect();
+        assert_eq!(lengths, vec![1, 0, 2]);
+    }
+
+    #[test]
+    fn test_length_lerp() {
+        use length::LengthUnit;
+
+        let a = Quantity::new(0.0, LengthUnit::Meters);
+        let b = Quantity::new(10.0, LengthUnit::Feet);
+
+        assert_eq!(length::lerp(a, b, 0.0).unwrap(), a);
+        let halfway = length::lerp(a, b, 0.5).unwrap();
+        assert_eq!(halfway.unit, Unit::Length(LengthUnit::Meters));
+        assert!((halfway.value - 1.524).abs() < 0.0001);
+        assert!((length::lerp(a, b, 1.0).unwrap().value - 3.048).abs() < 0.0001);
+
+        // Cross-category and out-of-range t are errors
+        let kg = Quantity::new(1.0, mass::MassUnit::Kilograms);
+        assert!(matches!(
+            length::lerp(a, kg, 0.5),
+            Err(Error::CategoryMismatch { .. })
+        ));
+        assert!(matches!(
+            length::lerp(a, b, 1.5),
+            Err(Error::InvalidInput(_))
+        ));
+    }
+
+    #[test]
+    fn test_convert_or_default_on_non_finite() {
+        use length::LengthUnit;
+
+        assert_eq!(
+            convert_or(f64::NAN, LengthUnit::Meters, LengthUnit::Feet, -1.0),
+            -1.0
+        );
+        assert_eq!(
+            convert_or(f64::INFINITY, LengthUnit::Meters, LengthUnit::Feet, -1.0),
+            -1.0
+        );
+
+        // Normal conversions are unaffected
+        let feet = convert_or(10.0, LengthUnit::Meters, LengthUnit::Feet, -1.0);
+        assert!((feet - 32.8084).abs() < 0.0001);
+
+        // Unconvertible units fall back too
+        assert_eq!(
+            convert_or(1.0, LengthUnit::Meters, mass::MassUnit::Kilograms, -1.0),
+            -1.0
+        );
+    }
+
+    #[test]
+    fn test_direct_conversions_match_base_routed() {
+        use length::LengthUnit;
+        use mass::MassUnit;
+        use pressure::PressureUnit;
+        use temperature::TemperatureUnit;
+
+        // The direct functions use factors rounded to about six significant
+        // figures, while the registry uses exact definitions, so agreement is
+        // checked to a relative tolerance of 1e-5 (with an absolute floor of
+        // 1e-5 for values near zero).
+        const TOLERANCE: f64 = 1e-5;
+
+        type Direct = (fn(f64) -> f64, Unit, Unit);
+        let direct: [Direct; 18] = [
+            (
+                length::meters_to_feet,
+                LengthUnit::Meters.into(),
+                LengthUnit::Feet.into(),
+            ),
+            (
+                length::feet_to_meters,
+                LengthUnit::Feet.into(),
+                LengthUnit::Meters.into(),
+            ),
+            (
+                length::km_to_miles,
+                LengthUnit::Kilometers.into(),
+                LengthUnit::Miles.into(),
+            ),
+            (
+                length::miles_to_km,
+                LengthUnit::Miles.into(),
+                LengthUnit::Kilometers.into(),
+            ),
+            (
+                temperature::celsius_to_fahrenheit,
+                TemperatureUnit::Celsius.into(),
+                TemperatureUnit::Fahrenheit.into(),
+            ),
+            (
+                temperature::fahrenheit_to_celsius,
+                TemperatureUnit::Fahrenheit.into(),
+                TemperatureUnit::Celsius.into(),
+            ),
+            (
+                temperature::celsius_to_kelvin,
+                TemperatureUnit::Celsius.into(),
+                TemperatureUnit::Kelvin.into(),
+            ),
+            (
+                temperature::kelvin_to_celsius,
+                TemperatureUnit::Kelvin.into(),
+                TemperatureUnit::Celsius.into(),
+            ),
+            (
+                temperature::celsius_to_delisle,
+                TemperatureUnit::Celsius.into(),
+                TemperatureUnit::Delisle.into(),
+            ),
+            (
+                temperature::delisle_to_celsius,
+                TemperatureUnit::Delisle.into(),
+                TemperatureUnit::Celsius.into(),
+            ),
+            (
+                pressure::bar_to_psi,
+                PressureUnit::Bar.into(),
+                PressureUnit::Psi.into(),
+            ),
+            (
+                pressure::psi_to_bar,
+                PressureUnit::Psi.into(),
+                PressureUnit::Bar.into(),
+            ),
+            (
+                pressure::pascal_to_bar,
+                PressureUnit::Pascal.into(),
+                PressureUnit::Bar.into(),
+            ),
+            (
+                pressure::bar_to_pascal,
+                PressureUnit::Bar.into(),
+                PressureUnit::Pascal.into(),
+            ),
+            (
+                mass::kg_to_pounds,
+                MassUnit::Kilograms.into(),
+                MassUnit::Pounds.into(),
+            ),
+            (
+                mass::pounds_to_kg,
+                MassUnit::Pounds.into(),
+                MassUnit::Kilograms.into(),
+            ),
+            (
+                mass::tonnes_to_tons,
+                MassUnit::Tonnes.into(),
+                MassUnit::Tons.into(),
+            ),
+            (
+                mass::tons_to_tonnes,
+                MassUnit::Tons.into(),
+                MassUnit::Tonnes.into(),
+            ),
+        ];
+
+        for (function, from, to) in direct {
+            for value in [-40.0, 0.0, 1.0, 37.5, 1000.0] {
+                let expected = convert(value, from, to).unwrap();
+                let actual = function(value);
+                assert!(
+                    (actual - expected).abs() <= TOLERANCE * expected.abs().max(1.0),
+                    "{} {} -> {}: direct {} vs base-routed {}",
+                    value,
+                    from,
+                    to,
+                    actual,
+                    expected
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_render_template() {
+        let _precision = PRECISION_LOCK.lock().unwrap();
+        let vars = HashMap::from([
+            (
+                "temp",
+                Quantity::new(55.0, temperature::TemperatureUnit::Celsius),
+            ),
+            ("pressure", Quantity::new(1.5, pressure::PressureUnit::Bar)),
+        ]);
+
+        let text = render_template("Operating at {temp:°F} and {pressure:psi}", &vars).unwrap();
+        assert_eq!(text, "Operating at 131.00 °F and 21.76 psi");
+
+        // Omitting the unit keeps the variable's own unit
+        assert_eq!(render_template("{temp}", &vars).unwrap(), "55.00 °C");
+
+        assert_eq!(
+            render_template("Flow {flow:m}", &vars),
+            Err(Error::UnknownPlaceholder("flow".to_string()))
+        );
+        assert!(matches!(
+            render_template("{temp:°F", &vars),
+            Err(Error::Parse(_))
+        ));
+    }
+
+    #[test]
+    fn test_length_conversion_pair() {
+        use length::LengthUnit;
+
+        let (to_feet, to_meters) = length::conversion_pair(LengthUnit::Meters, LengthUnit::Feet);
+        for meters in [0.0, 1.0, 42.195, -7.5, 1000.0] {
+            // Matches the direct functions
+            assert!(
+                (to_feet(meters) - length::meters_to_feet(meters)).abs()
+                    < 0.0001 * meters.abs().max(1.0)
+            );
+            assert!(
+                (to_feet(meters) - length::convert(meters, LengthUnit::Meters, LengthUnit::Feet))
+                    .abs()
+                    < 1e-9
+            );
+
+            // Round trips
+            assert!((to_meters(to_feet(meters)) - meters).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn test_default_precision() {
+        let _precision = PRECISION_LOCK.lock().unwrap();
+        let vars = HashMap::from([("d", Quantity::new(1.0, length::LengthUnit::Meters))]);
+
+        assert_eq!(default_precision(), 2);
+        assert_eq!(render_template("{d:ft}", &vars).unwrap(), "3.28 ft");
+
+        set_default_precision(4);
+        assert_eq!(render_template("{d:ft}", &vars).unwrap(), "3.2808 ft");
+
+        set_default_precision(2);
+    }
+
+    #[test]
+    fn test_normalize_text() {
+        let _precision = PRECISION_LOCK.lock().unwrap();
+
+        assert_eq!(
+            normalize_text("The room is 3 m wide", Region::Imperial),
+            "The room is 9.84 ft wide"
+        );
+
+        // Several quantities, punctuation and other text are preserved
+        assert_eq!(
+            normalize_text("Tank: 2 bar, 25 °C.  Done", Region::Imperial),
+            "Tank: 29.01 psi, 77.00 °F.  Done"
+        );
+        assert_eq!(
+            normalize_text("Walk 2 miles north", Region::Metric),
+            "Walk 3.22 km north"
+        );
+
+        // Text without quantities is untouched
+        let plain = "No numbers here, just 42 reasons";
+        assert_eq!(normalize_text(plain, Region::Imperial), plain);
+    }
+
+    #[test]
+    fn test_normalize_text_ignores_words_and_target_units() {
+        let _precision = PRECISION_LOCK.lock().unwrap();
+
+        // Ordinary words that happen to be unit symbols
+        for (text, region) in [
+            ("We have 2 in stock", Region::Metric),
+            ("Grade 3 C students", Region::Imperial),
+            ("Plan 9 F section", Region::Metric),
+            ("Only 5 g left", Region::Imperial),
+        ] {
+            assert_eq!(normalize_text(text, region), text);
+        }
+
+        // Quantities already in the target system keep their formatting
+        assert_eq!(
+            normalize_text("A 3 ft board and 2 m of rope", Region::Imperial),
+            "A 3 ft board and 6.56 ft of rope"
+        );
+        assert_eq!(
+            normalize_text("Took 2 inches off", Region::Metric),
+            "Took 0.05 m off"
+        );
+    }
+
+    #[test]
+    fn test_mass_weighted_average() {
+        let average = mass::weighted_average(
+            &[
+                (Quantity::new(10.0, mass::MassUnit::Kilograms), 3.0),
+                (Quantity::new(20.0, mass::MassUnit::Kilograms), 1.0),
+            ],
+            mass::MassUnit::Kilograms,
+        )
+        .unwrap();
+        assert_quantity_eq!(
+            average,
+            Quantity::new(12.5, mass::MassUnit::Kilograms),
+            1e-9
+        );
+
+        let zero = mass::weighted_average(
+            &[(Quantity::new(10.0, mass::MassUnit::Kilograms), 0.0)],
+            mass::MassUnit::Pounds,
+        );
+        assert_eq!(zero, Err(Error::DivisionByZero));
+        assert_eq!(
+            mass::weighted_average(&[], mass::MassUnit::Pounds),
+            Err(Error::DivisionByZero)
+        );
+    }
+
+    #[test]
+    fn test_dimension() {
+        use length::LengthUnit;
+
+        assert_eq!(dimension(LengthUnit::Meters), [1, 0, 0, 0, 0, 0, 0]);
+        assert_eq!(dimension(LengthUnit::Miles)[0], 1);
+        assert_eq!(dimension(mass::MassUnit::Pounds), [0, 1, 0, 0, 0, 0, 0]);
+        assert_ne!(
+            dimension(LengthUnit::Meters),
+            dimension(mass::MassUnit::Kilograms)
+        );
+
+        assert_eq!(
+            convert(1.0, LengthUnit::Meters, mass::MassUnit::Kilograms),
+            Err(Error::CategoryMismatch {
+                from: Category::Length,
+                to: Category::Mass,
+            })
+        );
+    }
+
+    #[test]
+    fn test_parse_tsv_column() {
+        use length::LengthUnit;
+
+        let data = "name\tlength_ft\tcount\nbeam\t10\t2\npost\t3.5\t4\n";
+        let meters = parse_tsv_column(data, 1, true, LengthUnit::Feet, LengthUnit::Meters).unwrap();
+        assert_eq!(meters.len(), 2);
+        assert!((meters[0] - 3.048).abs() < 1e-9);
+        assert!((meters[1] - 1.0668).abs() < 1e-9);
+
+        // Without skipping, the header is reported on line 1
+        assert_eq!(
+            parse_tsv_column(data, 1, false, LengthUnit::Feet, LengthUnit::Meters),
+            Err(Error::Parse(
+                "line 1: invalid number \"length_ft\"".to_string()
+            ))
+        );
+
+        let short = "a\t1\nb\n";
+        assert_eq!(
+            parse_tsv_column(short, 1, false, LengthUnit::Feet, LengthUnit::Meters),
+            Err(Error::Parse("line 2: missing column 1".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_measurement_system() {
+        use length::LengthUnit;
+
+        assert_eq!(system(LengthUnit::Feet), MeasurementSystem::Imperial);
+        assert_eq!(system(LengthUnit::Meters), MeasurementSystem::Metric);
+        assert_eq!(system(mass::MassUnit::Tons), MeasurementSystem::US);
+
+        let meters = Quantity::new(10.0, LengthUnit::Meters);
+        assert_eq!(meters.system(), MeasurementSystem::Metric);
+        let feet = meters.to(LengthUnit::Feet).unwrap();
+        assert_eq!(feet.system(), MeasurementSystem::Imperial);
+        assert!((feet.value - 32.8084).abs() < 1e-3);
+        assert!(meters.to(mass::MassUnit::Pounds).is_err());
+
+        // Classifications double as output targets
+        use volume::VolumeUnit;
+        let liters = Unit::Volume(VolumeUnit::Liters);
+        assert_eq!(
+            liters.in_region(system(VolumeUnit::UsGallons)),
+            VolumeUnit::UsGallons.into()
+        );
+        assert_eq!(
+            liters.in_region(Region::Imperial),
+            VolumeUnit::ImperialGallons.into()
+        );
+        assert_eq!(
+            Unit::Length(LengthUnit::Meters).in_region(Region::US),
+            LengthUnit::Feet.into()
+        );
+        let delisle = Unit::Temperature(temperature::TemperatureUnit::Delisle);
+        assert_eq!(delisle.in_region(MeasurementSystem::Other), delisle);
+    }
+
+    #[test]
+    fn test_energy_to_fuel_equivalent() {
+        use energy::Fuel;
+
+        let diesel = energy::to_fuel_equivalent(energy::kwh_to_joules(1.0), Fuel::Diesel);
+        assert!((diesel - 0.078947).abs() < 1e-6);
+
+        // Less energy-dense fuels need more mass for the same energy
+        let wood = energy::to_fuel_equivalent(energy::kwh_to_joules(1.0), Fuel::Wood);
+        assert!(wood > diesel);
+        assert_eq!(energy::to_fuel_equivalent(0.0, Fuel::Coal), 0.0);
+    }
+
+    #[test]
+    fn test_compound_unit_simplify() {
+        use compound::CompoundUnit;
+
+        let pressure: CompoundUnit = "N/m²".parse().unwrap();
+        assert_eq!(pressure.simplify().to_string(), "Pa");
+        let power: CompoundUnit = "J/s".parse().unwrap();
+        assert_eq!(power.simplify().to_string(), "W");
+        let energy = CompoundUnit::new(&[("N", 1), ("m", 1)]).unwrap();
+        assert_eq!(energy.simplify().to_string(), "J");
+
+        // Without a named match, repeated symbols are merged
+        let area: CompoundUnit = "m·m·s/s^1".parse().unwrap();
+        assert_eq!(area.simplify().to_string(), "m²");
+        let per_area_time: CompoundUnit = "1/m^2·s".parse().unwrap();
+        assert_eq!(per_area_time.dimension(), [-2, 0, -1, 0, 0, 0, 0]);
+        assert_eq!(per_area_time.to_string(), "1/m²·s");
+
+        assert_eq!(
+            "N/ft²".parse::<CompoundUnit>(),
+            Err(Error::UnknownUnit("ft".to_string()))
+        );
+
+        // Fully cancelled units are dimensionless
+        let ratio: CompoundUnit = "m/m".parse().unwrap();
+        assert_eq!(ratio.simplify().to_string(), "1");
+    }
+
+    #[test]
+    fn test_compound_unit_exponent_overflow() {
+        use compound::CompoundUnit;
+
+        for s in ["1/s^-128", "J^100", "m^100·m^100", "kg^127/kg^-1"] {
+            assert!(
+                matches!(s.parse::<CompoundUnit>(), Err(Error::Parse(_))),
+                "{}",
+                s
+            );
+        }
+        assert!(matches!(
+            CompoundUnit::new(&[("W", 50)]),
+            Err(Error::Parse(_))
+        ));
+
+        // The extremes of i8 still parse and display
+        let extreme: CompoundUnit = "s^-128".parse().unwrap();
+        assert_eq!(extreme.dimension()[2], -128);
+        assert_eq!(extreme.to_string(), "1/s¹²⁸");
+    }
+
+    #[test]
+    fn test_convert_with_error_budget() {
+        use length::LengthUnit;
+
+        let short = [
+            Unit::from(LengthUnit::Meters),
+            LengthUnit::Feet.into(),
+            LengthUnit::Inches.into(),
+        ];
+        let inches = convert_with_error_budget(1.0, &short, 1e-12).unwrap();
+        assert!((inches - 39.3701).abs() < 1e-4);
+
+        let long: Vec<Unit> = (0..1001)
+            .map(|i| match i % 2 {
+                0 => LengthUnit::Meters.into(),
+                _ => LengthUnit::Feet.into(),
+            })
+            .collect();
+        assert!(matches!(
+            convert_with_error_budget(1.0, &long, 1e-13),
+            Err(Error::ErrorBudgetExceeded { .. })
+        ));
+
+        assert!(matches!(
+            convert_with_error_budget(1.0, &short[..1], 1e-12),
+            Err(Error::InvalidInput(_))
+        ));
+    }
+
+    #[test]
+    fn test_convert_with_error_budget_affine_cancellation() {
+        use temperature::TemperatureUnit;
+
+        let path = [
+            Unit::from(TemperatureUnit::Celsius),
+            TemperatureUnit::Fahrenheit.into(),
+        ];
+        let boiling = convert_with_error_budget(100.0, &path, 1e-14).unwrap();
+        assert!((boiling - 212.0).abs() < 1e-12);
+
+        // Ending near 0 °F cancels the offset, so the relative error explodes
+        assert!(matches!(
+            convert_with_error_budget(-17.7777, &path, 1e-11),
+            Err(Error::ErrorBudgetExceeded { .. })
+        ));
+        match convert_with_error_budget(temperature::fahrenheit_to_celsius(0.0), &path, 1.0) {
+            Err(Error::ErrorBudgetExceeded { estimated, .. }) => assert!(estimated > 1.0),
+            Ok(fahrenheit) => panic!("expected the budget to be exceeded, got {}", fahrenheit),
+            Err(other) => panic!("unexpected error {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_parse_csv_with_unit_headers() {
+        let data = "length_m,temp_C\n1,0\n10,100\n";
+        let columns = parse_csv_with_unit_headers(data, Region::Imperial).unwrap();
+
+        assert_eq!(columns.len(), 2);
+        let (length_header, feet) = &columns[0];
+        assert_eq!(length_header, "length_ft");
+        assert!((feet[0] - 3.28084).abs() < 1e-4);
+        assert!((feet[1] - 32.8084).abs() < 1e-4);
+
+        let (temp_header, fahrenheit) = &columns[1];
+        assert_eq!(temp_header, "temp_°F");
+        assert!((fahrenheit[0] - 32.0).abs() < 1e-9);
+        assert!((fahrenheit[1] - 212.0).abs() < 1e-9);
+
+        assert_eq!(
+            parse_csv_with_unit_headers("length\n1\n", Region::Imperial),
+            Err(Error::Parse(
+                "column \"length\" has no unit suffix".to_string()
+            ))
+        );
+        assert_eq!(
+            parse_csv_with_unit_headers("length_m\nabc\n", Region::Imperial),
+            Err(Error::Parse("line 2: invalid number \"abc\"".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_quantity_scale_type_arithmetic() {
+        use length::LengthUnit;
+        use temperature::TemperatureUnit;
+
+        assert_eq!(scale_type(TemperatureUnit::Celsius), ScaleType::Interval);
+        assert_eq!(scale_type(TemperatureUnit::Kelvin), ScaleType::Ratio);
+        assert_eq!(scale_type(LengthUnit::Meters), ScaleType::Ratio);
+
+        let meters = Quantity::new(1.5, LengthUnit::Meters);
+        assert_eq!(meters * 2.0, Ok(Quantity::new(3.0, LengthUnit::Meters)));
+        assert_eq!(meters / 3.0, Ok(Quantity::new(0.5, LengthUnit::Meters)));
+        assert_eq!(meters / 0.0, Err(Error::DivisionByZero));
+
+        let celsius = Quantity::new(20.0, TemperatureUnit::Celsius);
+        assert_eq!(
+            celsius * 2.0,
+            Err(Error::InvalidOperationForScale(
+                TemperatureUnit::Celsius.into()
+            ))
+        );
+        assert!((celsius / 2.0).is_err());
+        assert!((Quantity::new(300.0, TemperatureUnit::Kelvin) * 2.0).is_ok());
+    }
+
+    #[test]
+    fn test_convert_with_provenance() {
+        use length::LengthUnit;
+
+        let before = std::time::SystemTime::now();
+        let (feet, provenance) =
+            convert_with_provenance(10.0, LengthUnit::Meters, LengthUnit::Feet).unwrap();
+
+        assert!((feet - 32.8084).abs() < 1e-4);
+        assert_eq!(provenance.input, Quantity::new(10.0, LengthUnit::Meters));
+        assert_eq!(provenance.output, Quantity::new(feet, LengthUnit::Feet));
+        assert!((provenance.factor - 1.0 / 0.3048).abs() < 1e-12);
+        assert_eq!(provenance.offset, 0.0);
+        assert_eq!(provenance.formula, format!("y = x × {}", provenance.factor));
+        assert!(provenance.timestamp >= before);
+
+        assert!(convert_with_provenance(1.0, LengthUnit::Feet, mass::MassUnit::Pounds).is_err());
+    }
+
+    #[test]
+    fn test_conversion_matrix() {
+        // Reciprocal products of linear factors should equal one to within a
+        // few ULPs: each factor is the quotient of two rounded base factors
+        const RECIPROCAL_TOLERANCE: f64 = 1e-12;
+
+        for category in Category::ALL {
+            let units: Vec<Unit> = Unit::all()
+                .into_iter()
+                .filter(|unit| unit.category() == category)
+                .collect();
+            let matrix: Vec<Vec<f64>> = units
+                .iter()
+                .map(|&from| {
+                    units
+                        .iter()
+                        .map(|&to| convert(1.0, from, to).unwrap())
+                        .collect()
+                })
+                .collect();
+
+            for (i, &unit) in units.iter().enumerate() {
+                assert_eq!(matrix[i][i], 1.0, "{} to itself", unit);
+            }
+
+            if category == Category::Temperature {
+                continue;
+            }
+            for i in 0..units.len() {
+                for j in 0..units.len() {
+                    let product = matrix[i][j] * matrix[j][i];
+                    assert!(
+                        (product - 1.0).abs() < RECIPROCAL_TOLERANCE,
+                        "{} and {} factors are not reciprocal: {}",
+                        units[i],
+                        units[j],
+                        product
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_angle_dms() {
+        assert_eq!(angle::dms_to_degrees(45, 30, 0.0), 45.5);
+        assert_eq!(angle::dms_to_degrees(-45, 30, 0.0), -45.5);
+        assert_eq!(angle::degrees_to_dms(45.5), (45, 30, 0.0));
+
+        // Round trip through the tuple form
+        let (d, m, s) = angle::degrees_to_dms(12.3456);
+        assert!((angle::dms_to_degrees(d, m, s) - 12.3456).abs() < 1e-12);
+        let (d, m, s) = angle::degrees_to_dms(-77.0365);
+        assert!((angle::dms_to_degrees(d, m, s) + 77.0365).abs() < 1e-12);
+
+        // Round trip through the string form, including the sign below 1°
+        for degrees in [45.5, 45.50416666666667, -0.25, -122.4194] {
+            let text = angle::format_dms(degrees, 3);
+            assert!(
+                (angle::parse_dms(&text).unwrap() - degrees).abs() < 1e-6,
+                "{}",
+                text
+            );
+        }
+        assert_eq!(angle::format_dms(45.5, 0), "45°30'00\"");
+
+        // Seconds rounding to 60 carry into minutes and degrees
+        assert_eq!(angle::format_dms(29.99999999, 2), "30°00'00.00\"");
+
+        assert!(angle::parse_dms("45°60'00\"").is_err());
+        assert!(angle::parse_dms("45.5").is_err());
+    }
+
+    #[test]
+    #[should_panic(expected = "minutes must be below 60")]
+    fn test_angle_dms_minutes_out_of_range() {
+        angle::dms_to_degrees(10, 60, 0.0);
+    }
+
+    #[test]
+    fn test_angle_dms_full_turn() {
+        assert_eq!(angle::degrees_to_dms(-360.0), (-360, 0, 0.0));
+        assert_eq!(angle::format_dms(360.0, 0), "360°00'00\"");
+
+        // Angles beyond a full turn are split as they are, not wrapped
+        assert_eq!(angle::degrees_to_dms(400.0), (400, 0, 0.0));
+        assert_eq!(angle::degrees_to_dms(-400.5), (-400, 30, 0.0));
+        assert_eq!(angle::format_dms(400.25, 0), "400°15'00\"");
+    }
+
+    #[test]
+    #[should_panic(expected = "angle must be finite and fit in i32 degrees")]
+    fn test_angle_dms_out_of_range() {
+        angle::degrees_to_dms(5e9);
+    }
+
+    #[test]
+    fn test_length_convert_rational() {
+        use length::LengthUnit;
+
+        assert_eq!(
+            length::convert_rational(1, 1, LengthUnit::Feet, LengthUnit::Meters),
+            (381, 1250)
+        );
+        assert_eq!(
+            length::convert_rational(1, 1, LengthUnit::Meters, LengthUnit::Feet),
+            (1250, 381)
+        );
+        assert_eq!(
+            length::convert_rational(1, 1, LengthUnit::Miles, LengthUnit::Feet),
+            (5280, 1)
+        );
+        assert_eq!(
+            length::convert_rational(6, -4, LengthUnit::Feet, LengthUnit::Inches),
+            (-18, 1)
+        );
+        assert_eq!(
+            length::convert_rational(0, 7, LengthUnit::Feet, LengthUnit::Meters),
+            (0, 1)
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "denominator must not be zero")]
+    fn test_length_convert_rational_zero_denominator() {
+        length::convert_rational(1, 0, length::LengthUnit::Feet, length::LengthUnit::Meters);
+    }
+
+    #[test]
+    fn test_celsius_to_kelvin_checked_slice() {
+        use temperature::TemperatureError;
+
+        let results =
+            temperature::celsius_to_kelvin_checked_slice(&[25.0, -273.15, -300.0, f64::NAN, 100.0]);
+
+        assert_eq!(results.len(), 5);
+        assert!((results[0].unwrap() - 298.15).abs() < 1e-9);
+        assert_eq!(results[1], Ok(0.0));
+        assert_eq!(results[2], Err(TemperatureError::BelowAbsoluteZero(-300.0)));
+        assert!(matches!(results[3], Err(TemperatureError::NotFinite(_))));
+        assert!((results[4].unwrap() - 373.15).abs() < 1e-9);
+
+        assert!(temperature::celsius_to_kelvin_checked_slice(&[]).is_empty());
+    }
+
+    #[test]
+    fn test_quantity_with_display_precision() {
+        use length::LengthUnit;
+
+        let meters = Quantity::new(1.0, LengthUnit::Meters).with_display_precision(1);
+        let feet = meters.to(LengthUnit::Feet).unwrap();
+        let inches = feet.to(LengthUnit::Inches).unwrap();
+
+        // Display rounds, the value carried through the chain does not
+        assert_eq!(feet.to_string(), "3.3 ft");
+        assert_eq!(inches.to_string(), "39.4 in");
+        assert!((inches.quantity.value - 1.0 / 0.0254).abs() < 1e-9);
+        assert_eq!(
+            inches.quantity,
+            Quantity::new(1.0, LengthUnit::Meters)
+                .to(LengthUnit::Feet)
+                .unwrap()
+                .to(LengthUnit::Inches)
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn test_pressure_to_gauge_color() {
+        let max = pressure::bar_to_pascal(10.0);
+
+        assert_eq!(pressure::to_gauge_color(0.0, max), (0, 255, 0));
+        assert_eq!(pressure::to_gauge_color(max / 2.0, max), (255, 255, 0));
+        assert_eq!(pressure::to_gauge_color(max, max), (255, 0, 0));
+        assert_eq!(pressure::to_gauge_color(max * 3.0, max), (255, 0, 0));
+        assert_eq!(pressure::to_gauge_color(-1.0, max), (0, 255, 0));
+        assert_eq!(pressure::to_gauge_color(max / 4.0, max), (128, 255, 0));
+    }
+
+    #[test]
+    #[should_panic(expected = "maximum pressure must be positive")]
+    fn test_pressure_to_gauge_color_zero_max() {
+        pressure::to_gauge_color(1.0, 0.0);
+    }
+
+    #[test]
+    fn test_load_factor_table() {
+        let table =
+            load_factor_table("from,to,factor\nmi,ft,5280\nft,in,12\n\nfurlong,ft,660\nlb,oz,16")
+                .unwrap();
+
+        assert_eq!(table.convert(1.0, "mi", "ft").unwrap(), 5280.0);
+        // Reverse and composed conversions
+        assert_eq!(table.convert(24.0, "in", "ft").unwrap(), 2.0);
+        assert_eq!(table.convert(1.0, "mi", "furlong").unwrap(), 8.0);
+        assert_eq!(table.convert(3.0, "oz", "oz").unwrap(), 3.0);
+
+        assert_eq!(
+            table.convert(1.0, "ft", "lb"),
+            Err(Error::NoConversionPath {
+                from: "ft".to_string(),
+                to: "lb".to_string(),
+            })
+        );
+        assert_eq!(
+            table.convert(1.0, "ft", "m"),
+            Err(Error::UnknownUnit("m".to_string()))
+        );
+
+        assert_eq!(
+            load_factor_table("ft,in,12\nft,in\n").unwrap_err(),
+            Error::Parse("line 2: expected from,to,factor".to_string())
+        );
+        assert!(load_factor_table("ft,in,0").is_err());
+    }
+
+    #[test]
+    fn test_electrical_resistance_at_temp() {
+        // Pt100 RTD referenced at 0 °C
+        let at_100 = electrical::resistance_at_temp(100.0, electrical::PLATINUM_ALPHA, 100.0, 0.0);
+        assert!((at_100 - 138.5).abs() < 1e-9);
+
+        let at_ref = electrical::resistance_at_temp(100.0, electrical::PLATINUM_ALPHA, 0.0, 0.0);
+        assert_eq!(at_ref, 100.0);
+
+        let below = electrical::resistance_at_temp(100.0, electrical::PLATINUM_ALPHA, -40.0, 0.0);
+        assert!((below - 84.6).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_precomputed_converter_matches_direct() {
+        for category in Category::ALL {
+            let converter = PrecomputedConverter::new(category);
+            let units: Vec<Unit> = Unit::all()
+                .into_iter()
+                .filter(|unit| unit.category() == category)
+                .collect();
+            for &from in &units {
+                for &to in &units {
+                    for value in [-40.0, 0.0, 1.0, 98.6, 12_345.0] {
+                        let direct = convert(value, from, to).unwrap();
+                        let precomputed = converter.convert(value, from, to).unwrap();
+                        assert!(
+                            (direct - precomputed).abs() <= 1e-9 * direct.abs().max(1.0),
+                            "{} {} -> {}: {} vs {}",
+                            value,
+                            from,
+                            to,
+                            direct,
+                            precomputed
+                        );
+                    }
+                }
+            }
+        }
+
+        let converter = PrecomputedConverter::new(Category::Pressure);
+        assert!(converter
+            .convert(1.0, length::LengthUnit::Meters, pressure::PressureUnit::Bar)
+            .is_err());
+    }
+
+    #[test]
+    fn test_precomputed_converter_throughput() {
+        use length::LengthUnit;
+
+        // Benchmark-style workload: many lookups give the same total as the
+        // legacy function
+        let converter = PrecomputedConverter::new(Category::Length);
+        let mut precomputed = 0.0;
+        let mut direct = 0.0;
+        for i in 0..100_000 {
+            let meters = f64::from(i) * 0.01;
+            precomputed += converter
+                .convert(meters, LengthUnit::Meters, LengthUnit::Kilometers)
+                .unwrap();
+            direct += meters / 1000.0;
+        }
+        assert!((precomputed - direct).abs() < 1e-6 * direct);
+    }
+
+    #[test]
+    fn test_explain_difference() {
+        let reversed = explain_difference(10.0, "m", "ft", length::feet_to_meters(10.0));
+        assert!(
+            reversed.contains("applied in reverse (ft to m)"),
+            "{}",
+            reversed
+        );
+
+        // 10 US gallons are 37.85 L, but 45.46 L if read as imperial gallons
+        let gallons = explain_difference(10.0, "gal_us", "L", 45.4609);
+        assert!(gallons.contains("US and imperial gallons"), "{}", gallons);
+
+        let survey = explain_difference(1000.0, "ft", "m", 1000.0 * length::US_SURVEY_FOOT_M);
+        assert!(survey.contains("US survey feet"), "{}", survey);
+
+        assert_eq!(
+            explain_difference(1.0, "km", "m", 1000.0),
+            "1000 m is correct"
+        );
+        let unexplained = explain_difference(1.0, "km", "m", 1100.0);
+        assert!(unexplained.contains("no known mistake"), "{}", unexplained);
+        assert!(explain_difference(1.0, "km", "kg", 1.0).starts_with("cannot check"));
+    }
+
+    #[test]
+    fn test_volume_units() {
+        use volume::VolumeUnit;
+
+        let liters = convert(1.0, VolumeUnit::UsGallons, VolumeUnit::Liters).unwrap();
+        assert!((liters - 3.785411784).abs() < 1e-9);
+        let cubic_feet = convert(1.0, VolumeUnit::CubicMeters, VolumeUnit::CubicFeet).unwrap();
+        assert!((cubic_feet - volume::cubic_meters_to_cubic_feet(1.0)).abs() < 1e-9);
+
+        assert_eq!("L".parse::<Unit>(), Ok(Unit::Volume(VolumeUnit::Liters)));
+        assert!("gal".parse::<Unit>().is_err());
+        assert_eq!(system(VolumeUnit::UsGallons), MeasurementSystem::US);
+    }
+
+    #[test]
+    fn test_running_stats() {
+        use length::LengthUnit;
+
+        let mut stats = RunningStats::new();
+        assert_eq!(stats.mean(), None);
+
+        for km in [2.0, 4.0, 4.0, 4.0, 5.0, 5.0, 7.0, 9.0] {
+            stats
+                .push(km, LengthUnit::Kilometers, LengthUnit::Meters)
+                .unwrap();
+        }
+        assert_eq!(stats.count(), 8);
+        assert_eq!(stats.unit(), Some(Unit::from(LengthUnit::Meters)));
+        assert!((stats.mean().unwrap() - 5000.0).abs() < 1e-9);
+        assert_eq!(stats.min(), Some(2000.0));
+        assert_eq!(stats.max(), Some(9000.0));
+        // Sum of squared deviations is 32 km², over n - 1 = 7
+        assert!((stats.variance().unwrap() - 32.0e6 / 7.0).abs() < 1e-3);
+        assert!((stats.std_dev().unwrap() - (32.0e6_f64 / 7.0).sqrt()).abs() < 1e-6);
+
+        assert!(stats.push(1.0, LengthUnit::Feet, LengthUnit::Feet).is_err());
+        assert!(stats
+            .push(1.0, mass::MassUnit::Pounds, LengthUnit::Meters)
+            .is_err());
+        assert_eq!(stats.count(), 8);
+    }
+
+    #[test]
+    fn test_parse_localized_number() {
+        assert_eq!(
+            parse_localized_number("1.234,56", NumberLocale::European),
+            Ok(1234.56)
+        );
+        assert_eq!(
+            parse_localized_number("1,234.56", NumberLocale::Us),
+            Ok(1234.56)
+        );
+        assert_eq!(
+            parse_localized_number("1 234,5", NumberLocale::French),
+            Ok(1234.5)
+        );
+        assert_eq!(
+            parse_localized_number("-12.345.678", NumberLocale::European),
+            Ok(-12_345_678.0)
+        );
+        assert_eq!(
+            parse_localized_number("0,5", NumberLocale::European),
+            Ok(0.5)
+        );
+        assert_eq!(parse_localized_number("42", NumberLocale::Us), Ok(42.0));
+
+        // The other locale's layout is rejected rather than misread
+        assert!(parse_localized_number("1,234.56", NumberLocale::European).is_err());
+        assert!(parse_localized_number("1,23.4", NumberLocale::Us).is_err());
+        assert!(parse_localized_number("", NumberLocale::Us).is_err());
+        assert!(parse_localized_number("12a", NumberLocale::Us).is_err());
+
+        let bar = parse_localized_number("2,5", NumberLocale::European).unwrap();
+        let psi = convert(
+            bar,
+            pressure::PressureUnit::Bar,
+            pressure::PressureUnit::Psi,
+        )
+        .unwrap();
+        assert!((psi - pressure::bar_to_psi(2.5)).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_fuel_economy_round_trip() {
+        for mpg in [0.5, 12.0, 30.0, 54.7, 235.0, 1_000.0] {
+            let back = fuel::l_per_100km_to_mpg(fuel::mpg_to_l_per_100km(mpg));
+            assert!((back - mpg).abs() <= 1e-12 * mpg, "{} -> {}", mpg, back);
+        }
+        for l_per_100km in [3.2, 7.8, 15.0] {
+            let back = fuel::mpg_to_l_per_100km(fuel::l_per_100km_to_mpg(l_per_100km));
+            assert!((back - l_per_100km).abs() <= 1e-12 * l_per_100km);
+        }
+
+        assert!((fuel::mpg_to_l_per_100km(fuel::MPG_TIMES_L_PER_100KM) - 1.0).abs() < 1e-15);
+
+        // Zero and infinity map onto each other in both directions
+        assert_eq!(fuel::mpg_to_l_per_100km(0.0), f64::INFINITY);
+        assert_eq!(fuel::l_per_100km_to_mpg(0.0), f64::INFINITY);
+        assert_eq!(fuel::mpg_to_l_per_100km(f64::INFINITY), 0.0);
+        assert_eq!(fuel::l_per_100km_to_mpg(f64::INFINITY), 0.0);
+    }
+
+    #[test]
+    fn test_typed_convert() {
+        use typed::{Celsius, Fahrenheit, Kilometers, Meters, Pounds, Tonnes};
+
+        let km: typed::Quantity<Kilometers> =
+            typed::convert(typed::Quantity::<Meters>::new(1500.0));
+        assert_eq!(km.value(), 1.5);
+        assert_eq!(km.to_string(), "1.5 km");
+
+        let fahrenheit: typed::Quantity<Fahrenheit> =
+            typed::convert(typed::Quantity::<Celsius>::new(100.0));
+        assert!((fahrenheit.value() - 212.0).abs() < 1e-9);
+
+        let tonnes = typed::Quantity::<Tonnes>::new(1.0);
+        let pounds: typed::Quantity<Pounds> = typed::convert(tonnes);
+        assert_eq!(
+            pounds.erase(),
+            Quantity::new(pounds.value(), mass::MassUnit::Pounds)
+        );
+        assert!((pounds.value() - 2204.62).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_length_optimal_unit_for_dataset() {
+        use length::LengthUnit;
+
+        let millimeter_scale = [0.0015, 0.008, 0.02, 0.34, 0.6];
+        assert_eq!(
+            length::optimal_unit_for_dataset(&millimeter_scale),
+            LengthUnit::Millimeters
+        );
+
+        let kilometer_scale = [1_200.0, 15_000.0, 42_195.0, 380_000.0];
+        assert_eq!(
+            length::optimal_unit_for_dataset(&kilometer_scale),
+            LengthUnit::Kilometers
+        );
+
+        assert_eq!(
+            length::optimal_unit_for_dataset(&[2.5, 40.0, 120.0]),
+            LengthUnit::Meters
+        );
+        assert_eq!(length::optimal_unit_for_dataset(&[]), LengthUnit::Meters);
+        assert_eq!(
+            length::optimal_unit_for_dataset(&[1e-12]),
+            LengthUnit::Meters
+        );
+
+        // The deprecated survey foot is never picked, even where it fits best
+        assert_ne!(
+            length::optimal_unit_for_dataset(&[304.8003, 0.5]),
+            LengthUnit::UsSurveyFeet
+        );
+
+        // 1.5 m is readable in cm, m, ft and yd; the first in ALL wins
+        assert_eq!(
+            length::optimal_unit_for_dataset(&[1.5]),
+            LengthUnit::Centimeters
+        );
+    }
+
+    #[test]
+    fn test_within_tolerance() {
+        use length::LengthUnit;
+        use temperature::TemperatureUnit;
+
+        let nominal = Quantity::new(100.0, LengthUnit::Millimeters);
+        let tolerance = Quantity::new(0.002, LengthUnit::Inches);
+
+        // 0.002 in is 0.0508 mm
+        let inside = Quantity::new(0.10005, LengthUnit::Meters);
+        assert_eq!(within_tolerance(inside, nominal, tolerance), Ok(true));
+        let outside = Quantity::new(100.051, LengthUnit::Millimeters);
+        assert_eq!(within_tolerance(outside, nominal, tolerance), Ok(false));
+
+        // Temperature tolerances are differences, not absolute temperatures
+        let oven = Quantity::new(180.5, TemperatureUnit::Celsius);
+        let setpoint = Quantity::new(356.0, TemperatureUnit::Fahrenheit);
+        let one_degree_f = Quantity::new(1.0, TemperatureUnit::Fahrenheit);
+        assert_eq!(within_tolerance(oven, setpoint, one_degree_f), Ok(true));
+        let half_degree_f = Quantity::new(0.5, TemperatureUnit::Fahrenheit);
+        assert_eq!(within_tolerance(oven, setpoint, half_degree_f), Ok(false));
+
+        // 3 °De is 2 K even though the Delisle scale runs backwards
+        let room = Quantity::new(21.0, TemperatureUnit::Celsius);
+        let three_delisle = Quantity::new(3.0, TemperatureUnit::Delisle);
+        assert_eq!(within_tolerance(room, room, three_delisle), Ok(true));
+        let warmer = Quantity::new(22.5, TemperatureUnit::Celsius);
+        assert_eq!(within_tolerance(warmer, room, three_delisle), Ok(true));
+        let too_warm = Quantity::new(23.5, TemperatureUnit::Celsius);
+        assert_eq!(within_tolerance(too_warm, room, three_delisle), Ok(false));
+
+        assert!(within_tolerance(
+            inside,
+            nominal,
+            Quantity::new(1.0, mass::MassUnit::Kilograms)
+        )
+        .is_err());
+        assert!(matches!(
+            within_tolerance(inside, nominal, Quantity::new(-1.0, LengthUnit::Meters)),
+            Err(Error::InvalidInput(_))
+        ));
+    }
+
+    #[test]
+    fn test_precise_quantity_parse() {
+        use length::LengthUnit;
+
+        let coarse: PreciseQuantity = "1.5 m".parse().unwrap();
+        let fine: PreciseQuantity = "1.50 m".parse().unwrap();
+        assert_eq!(coarse.precision, 1);
+        assert_eq!(fine.precision, 2);
+        assert_eq!(coarse.quantity, fine.quantity);
+
+        assert_eq!(coarse.to(LengthUnit::Feet).unwrap().to_string(), "4.9 ft");
+        assert_eq!(fine.to(LengthUnit::Feet).unwrap().to_string(), "4.92 ft");
+
+        let whole: PreciseQuantity = "20 °C".parse().unwrap();
+        assert_eq!(
+            whole
+                .to(temperature::TemperatureUnit::Fahrenheit)
+                .unwrap()
+                .to_string(),
+            "68 °F"
+        );
+
+        // Exponents move the decimal point instead of adding precision
+        let kilo: PreciseQuantity = "1.5e3 m".parse().unwrap();
+        assert_eq!(kilo.precision, 0);
+        assert_eq!(kilo.to_string(), "1500 m");
+        let milli: PreciseQuantity = "1.5E-3 m".parse().unwrap();
+        assert_eq!(milli.precision, 4);
+        assert_eq!(milli.to_string(), "0.0015 m");
+        let exact: PreciseQuantity = "2.50e1 m".parse().unwrap();
+        assert_eq!(exact.to_string(), "25.0 m");
+
+        assert!("1.5".parse::<PreciseQuantity>().is_err());
+        assert_eq!(
+            "1.5 parsecs".parse::<PreciseQuantity>(),
+            Err(Error::UnknownUnit("parsecs".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_flow_mass_to_volumetric() {
+        assert_eq!(flow::mass_to_volumetric(1000.0, 1000.0), Ok(1.0));
+        assert_eq!(flow::volumetric_to_mass(1.0, 1000.0), Ok(1000.0));
+
+        // Diesel at 832 kg/m³ round-trips
+        let m3_per_h = flow::mass_to_volumetric(2500.0, 832.0).unwrap();
+        let kg_per_h = flow::volumetric_to_mass(m3_per_h, 832.0).unwrap();
+        assert!((kg_per_h - 2500.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_flow_mass_to_volumetric_invalid_density() {
+        for density in [0.0, -832.0, f64::NAN, f64::INFINITY] {
+            assert!(matches!(
+                flow::mass_to_volumetric(1000.0, density),
+                Err(Error::InvalidInput(_))
+            ));
+            assert!(matches!(
+                flow::volumetric_to_mass(1.0, density),
+                Err(Error::InvalidInput(_))
+            ));
+        }
+    }
+
+    #[test]
+    fn test_accuracy_report() {
+        use length::LengthUnit;
+
+        // A linear conversion loses at most a few f32 ULPs
+        let error = accuracy_report((LengthUnit::Meters, LengthUnit::Feet)).unwrap();
+        assert!(error > 0.0);
+        assert!(error < 4.0 * f64::from(f32::EPSILON), "{}", error);
+
+        let identity = accuracy_report((LengthUnit::Meters, LengthUnit::Meters)).unwrap();
+        assert!(identity <= f64::from(f32::EPSILON));
+
+        assert!(accuracy_report((LengthUnit::Meters, mass::MassUnit::Kilograms)).is_err());
+    }
+
+    #[test]
+    fn test_quantized_cache() {
+        use length::LengthUnit;
+
+        let mut cache = QuantizedCache::new(0.01);
+        assert!(cache.is_empty());
+
+        let first = cache
+            .convert(2.0003, LengthUnit::Meters, LengthUnit::Feet)
+            .unwrap();
+        let jittered = cache
+            .convert(1.9996, LengthUnit::Meters, LengthUnit::Feet)
+            .unwrap();
+        assert_eq!(first, jittered);
+        assert_eq!(
+            first,
+            convert(2.0, LengthUnit::Meters, LengthUnit::Feet).unwrap()
+        );
+        assert_eq!(cache.len(), 1);
+
+        // A different quantum step or unit pair is a new entry
+        cache
+            .convert(2.01, LengthUnit::Meters, LengthUnit::Feet)
+            .unwrap();
+        cache
+            .convert(2.0, LengthUnit::Meters, LengthUnit::Inches)
+            .unwrap();
+        assert_eq!(cache.len(), 3);
+
+        assert!(cache
+            .convert(f64::NAN, LengthUnit::Meters, LengthUnit::Feet)
+            .unwrap()
+            .is_nan());
+        assert!(cache
+            .convert(1.0, LengthUnit::Meters, mass::MassUnit::Pounds)
+            .is_err());
+        assert_eq!(cache.len(), 3);
+    }
+
+    #[test]
+    fn test_quantized_cache_large_values_bypass() {
+        use length::LengthUnit;
+
+        let mut cache = QuantizedCache::new(0.01);
+        let (m, km) = (LengthUnit::Meters, LengthUnit::Kilometers);
+        assert!((cache.convert(1e30, m, km).unwrap() - 1e27).abs() < 1e12);
+        assert!((cache.convert(1e40, m, km).unwrap() - 1e37).abs() < 1e22);
+        assert!((cache.convert(-1e40, m, km).unwrap() + 1e37).abs() < 1e22);
+        assert!(cache.is_empty());
+
+        // Values whose steps still fit are cached as before
+        cache.convert(1e15, m, km).unwrap();
+        assert_eq!(cache.len(), 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "quantum must be positive and finite")]
+    fn test_quantized_cache_zero_quantum() {
+        QuantizedCache::new(0.0);
+    }
+
+    #[test]
+    fn test_kelvin_new() {
+        use temperature::{Kelvin, TemperatureError};
+
+        assert_eq!(Kelvin::new(0.0).unwrap().value(), 0.0);
+        assert!(matches!(
+            Kelvin::new(-5.0),
+            Err(TemperatureError::BelowAbsoluteZero(_))
+        ));
+        assert!(matches!(
+            Kelvin::new(f64::NAN),
+            Err(TemperatureError::NotFinite(_))
+        ));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_kelvin_serde_validation() {
+        use temperature::Kelvin;
+
+        let kelvin: Kelvin = serde_json::from_str("293.15").unwrap();
+        assert_eq!(kelvin.value(), 293.15);
+        assert_eq!(serde_json::to_string(&kelvin).unwrap(), "293.15");
+
+        let err = serde_json::from_str::<Kelvin>("-10").unwrap_err();
+        assert!(err.to_string().contains("below absolute zero"));
+    }
+
+    #[test]
+    fn test_geo_distances() {
+        let one_degree = geo::lat_degrees_to_meters(1.0);
+        assert!((one_degree / 1000.0 - 111.0).abs() < 0.5);
+        assert!((geo::meters_to_lat_degrees(one_degree) - 1.0).abs() < 1e-12);
+
+        assert!((geo::lon_degrees_to_meters(1.0, 0.0) - one_degree).abs() < 1e-9);
+        assert!((geo::lon_degrees_to_meters(1.0, 60.0) - one_degree / 2.0).abs() < 1e-6);
+        assert!(geo::lon_degrees_to_meters(1.0, 80.0) < geo::lon_degrees_to_meters(1.0, 45.0));
+        assert!(geo::lon_degrees_to_meters(1.0, 90.0).abs() < 1e-9);
+
+        let meters = geo::lon_degrees_to_meters(2.5, 45.0);
+        assert!((geo::meters_to_lon_degrees(meters, 45.0) - 2.5).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_convert_identity_is_bit_exact() {
+        use length::LengthUnit;
+
+        let subnormal = f64::from_bits(1);
+        assert!(subnormal.is_subnormal());
+        for value in [subnormal, 1.0 / 3.0, -0.0, 98.6] {
+            for unit in Unit::all() {
+                assert_eq!(
+                    convert(value, unit, unit).unwrap().to_bits(),
+                    value.to_bits()
+                );
+            }
+        }
+        let meters = convert(subnormal, LengthUnit::Meters, LengthUnit::Meters).unwrap();
+        assert_eq!(meters.to_bits(), subnormal.to_bits());
+    }
+
+    #[test]
+    fn test_mass_humanize() {
+        use mass::MassUnit;
+
+        let (grams, unit) = mass::humanize(0.002);
+        assert_eq!(unit, MassUnit::Grams);
+        assert!((grams - 2.0).abs() < 1e-12);
+        assert_eq!(mass::humanize(50.0), (50.0, MassUnit::Kilograms));
+        assert_eq!(mass::humanize(5000.0), (5.0, MassUnit::Tonnes));
+        assert_eq!(mass::humanize(0.0), (0.0, MassUnit::Kilograms));
+        assert_eq!(mass::humanize(-2500.0), (-2.5, MassUnit::Tonnes));
+        assert_eq!("g".parse::<MassUnit>().unwrap(), MassUnit::Grams);
+    }
+
+    #[test]
+    fn test_eval_expression() {
+        use length::LengthUnit;
+
+        let total = eval_expression("2 m + 30 cm").unwrap();
+        assert_eq!(total.unit, Unit::Length(LengthUnit::Meters));
+        assert!((total.value - 2.3).abs() < 1e-12);
+
+        let remaining = eval_expression("1 km - 250 m \u{2212} 0.5 km").unwrap();
+        assert_eq!(remaining.unit, Unit::Length(LengthUnit::Kilometers));
+        assert!((remaining.value - 0.25).abs() < 1e-12);
+
+        assert_eq!(
+            eval_expression("12 in").unwrap(),
+            Quantity::new(12.0, LengthUnit::Inches)
+        );
+        assert!(matches!(
+            eval_expression("2 m + 3 kg"),
+            Err(Error::CategoryMismatch { .. })
+        ));
+        assert!(matches!(
+            eval_expression("20 °C + 5 °C"),
+            Err(Error::InvalidOperationForScale(_))
+        ));
+        assert!(matches!(eval_expression("2 m * 3 m"), Err(Error::Parse(_))));
+        assert!(matches!(eval_expression("2 m +"), Err(Error::Parse(_))));
+        assert!(matches!(eval_expression(""), Err(Error::Parse(_))));
+    }
+
+    #[test]
+    fn test_temperature_ratio() {
+        use temperature::TemperatureUnit;
+
+        let kelvin = Quantity::new(373.15, TemperatureUnit::Kelvin);
+        let freezing = Quantity::new(273.15, TemperatureUnit::Kelvin);
+        let ratio = temperature::ratio(kelvin, freezing).unwrap();
+        assert!((ratio - 373.15 / 273.15).abs() < 1e-12);
+
+        let celsius = Quantity::new(100.0, TemperatureUnit::Celsius);
+        assert!(matches!(
+            temperature::ratio(celsius, freezing),
+            Err(Error::RatioRequiresAbsoluteScale(Unit::Temperature(
+                TemperatureUnit::Celsius
+            )))
+        ));
+        assert!(matches!(
+            temperature::ratio(freezing, Quantity::new(0.0, TemperatureUnit::Kelvin)),
+            Err(Error::DivisionByZero)
+        ));
+        assert!(matches!(
+            temperature::ratio(freezing, Quantity::new(1.0, mass::MassUnit::Kilograms)),
+            Err(Error::CategoryMismatch { .. })
+        ));
+    }
+
+    #[test]
+    fn test_to_spreadsheet_formula() {
+        use length::LengthUnit;
+        use temperature::TemperatureUnit;
+
+        assert_eq!(
+            length::to_spreadsheet_formula("A1", LengthUnit::Meters, LengthUnit::Feet),
+            "=A1*3.280839895013123"
+        );
+        assert_eq!(
+            length::to_spreadsheet_formula("C3", LengthUnit::Kilometers, LengthUnit::Meters),
+            "=C3*1000"
+        );
+        assert_eq!(
+            length::to_spreadsheet_formula("A1", LengthUnit::Feet, LengthUnit::Feet),
+            "=A1"
+        );
+
+        assert_eq!(
+            temperature::to_spreadsheet_formula(
+                "A1",
+                TemperatureUnit::Celsius,
+                TemperatureUnit::Fahrenheit
+            ),
+            "=(A1*9/5)+32"
+        );
+        assert_eq!(
+            temperature::to_spreadsheet_formula(
+                "A1",
+                TemperatureUnit::Kelvin,
+                TemperatureUnit::Celsius
+            ),
+            "=A1-273.15"
+        );
+        assert_eq!(
+            temperature::to_spreadsheet_formula(
+                "A1",
+                TemperatureUnit::Delisle,
+                TemperatureUnit::Fahrenheit
+            ),
+            "=((100-A1*2/3)*9/5)+32"
+        );
+        assert_eq!(
+            temperature::to_spreadsheet_formula(
+                "A1",
+                TemperatureUnit::Celsius,
+                TemperatureUnit::Delisle
+            ),
+            "=(100-A1)*3/2"
+        );
+    }
+
+    #[test]
+    fn test_is_exact() {
+        use length::LengthUnit;
+        use pressure::PressureUnit;
+
+        assert!(is_exact(LengthUnit::Meters, LengthUnit::Feet).unwrap());
+        assert!(is_exact(LengthUnit::Miles, LengthUnit::UsSurveyFeet).unwrap());
+        assert!(!is_exact(mass::MassUnit::Kilograms, mass::MassUnit::Pounds).unwrap());
+        assert!(!is_exact(
+            temperature::TemperatureUnit::Celsius,
+            temperature::TemperatureUnit::Fahrenheit
+        )
+        .unwrap());
+        assert!(!is_exact(PressureUnit::Bar, PressureUnit::Psi).unwrap());
+        assert!(is_exact(PressureUnit::Psi, PressureUnit::Psi).unwrap());
+        assert!(is_exact(LengthUnit::Meters, PressureUnit::Bar).is_err());
+    }
+
+    #[test]
+    fn test_length_normalize_prefix() {
+        use length::LengthUnit;
+
+        let micro = length::normalize_prefix(Quantity::new(0.0000123, LengthUnit::Meters));
+        assert_eq!(micro.unit, Unit::Length(LengthUnit::Micrometers));
+        assert!((micro.value - 12.3).abs() < 1e-9);
+
+        let kilo = length::normalize_prefix(Quantity::new(-25_000.0, LengthUnit::Feet));
+        assert_eq!(kilo.unit, Unit::Length(LengthUnit::Kilometers));
+        assert!((kilo.value + 7.62).abs() < 1e-9);
+
+        let milli = length::normalize_prefix(Quantity::new(0.5, LengthUnit::Centimeters));
+        assert_eq!(milli.unit, Unit::Length(LengthUnit::Millimeters));
+        assert!((milli.value - 5.0).abs() < 1e-9);
+
+        let tiny = length::normalize_prefix(Quantity::new(1e-9, LengthUnit::Meters));
+        assert_eq!(tiny.unit, Unit::Length(LengthUnit::Micrometers));
+
+        let zero = Quantity::new(0.0, LengthUnit::Miles);
+        assert_eq!(length::normalize_prefix(zero), zero);
+        let mass = Quantity::new(0.001, mass::MassUnit::Kilograms);
+        assert_eq!(length::normalize_prefix(mass), mass);
+        assert_eq!("um".parse::<LengthUnit>().unwrap(), LengthUnit::Micrometers);
+    }
+
+    #[test]
+    fn test_convert_any_with_provider() {
+        struct Surveying;
+
+        impl UnitProvider for Surveying {
+            fn units(&self) -> Vec<String> {
+                vec![
+                    "chain".to_string(),
+                    "furlong".to_string(),
+                    "acre".to_string(),
+                ]
+            }
+
+            fn category(&self, symbol: &str) -> Option<String> {
+                match symbol {
+                    "chain" | "furlong" => Some("SurveyLength".to_string()),
+                    "acre" => Some("SurveyArea".to_string()),
+                    _ => None,
+                }
+            }
+
+            fn convert_to_base(&self, value: f64, symbol: &str) -> Option<f64> {
+                match symbol {
+                    "chain" => Some(value),
+                    "furlong" => Some(value * 10.0),
+                    "acre" => Some(value),
+                    _ => None,
+                }
+            }
+
+            fn convert_from_base(&self, value: f64, symbol: &str) -> Option<f64> {
+                self.convert_to_base(1.0, symbol)
+                    .map(|factor| value / factor)
+            }
+        }
+
+        assert!(Surveying.units().contains(&"furlong".to_string()));
+        assert!(BuiltinUnits.units().contains(&"km".to_string()));
+        assert!(matches!(
+            convert_any(1.0, "furlong", "chain"),
+            Err(Error::UnknownUnit(_))
+        ));
+
+        register_provider(Box::new(Surveying));
+        assert_eq!(convert_any(3.0, "furlong", "chain").unwrap(), 30.0);
+        assert_eq!(convert_any(1.0, "km", "m").unwrap(), 1000.0);
+        assert!(matches!(
+            convert_any(1.0, "furlong", "acre"),
+            Err(Error::NoConversionPath { .. })
+        ));
+        assert!(matches!(
+            convert_any(1.0, "furlong", "m"),
+            Err(Error::NoConversionPath { .. })
+        ));
+        assert!(matches!(
+            convert_any(1.0, "parsec", "m"),
+            Err(Error::UnknownUnit(symbol)) if symbol == "parsec"
+        ));
+    }
+
+    #[test]
+    fn test_reciprocal_period_frequency() {
+        assert_eq!(time::period_to_frequency(2.0).unwrap(), 0.5);
+        assert_eq!(time::frequency_to_period(0.5).unwrap(), 2.0);
+        for period in [0.001, 0.25, 3.0, 86_400.0] {
+            let hz = time::period_to_frequency(period).unwrap();
+            assert!((time::frequency_to_period(hz).unwrap() - period).abs() < 1e-12 * period);
+        }
+        assert!(matches!(
+            time::period_to_frequency(0.0),
+            Err(Error::DivisionByZero)
+        ));
+        assert!(matches!(
+            time::frequency_to_period(-0.0),
+            Err(Error::DivisionByZero)
+        ));
+
+        assert_eq!(time::rpm_to_period(3000.0).unwrap(), 0.02);
+        assert_eq!(time::period_to_rpm(0.02).unwrap(), 3000.0);
+        assert!(time::rpm_to_period(0.0).is_err());
+    }
+
+    #[test]
+    fn test_conversion_kind_between() {
+        use length::LengthUnit;
+        use temperature::TemperatureUnit;
+
+        assert_eq!(
+            ConversionKind::between(LengthUnit::Kilometers, LengthUnit::Meters).unwrap(),
+            ConversionKind::Linear { factor: 1000.0 }
+        );
+        let kind =
+            ConversionKind::between(TemperatureUnit::Celsius, TemperatureUnit::Kelvin).unwrap();
+        assert!(matches!(kind, ConversionKind::Affine { .. }));
+        assert!((kind.apply(25.0).unwrap() - 298.15).abs() < 1e-9);
+        assert!(ConversionKind::between(LengthUnit::Meters, TemperatureUnit::Kelvin).is_err());
+    }
+
+    #[test]
+    fn test_length_convert_split() {
+        use length::LengthUnit;
+
+        let input: Vec<f64> = (0..1000).map(|i| i as f64 * 0.5).collect();
+        let (originals, feet) = length::convert_split(&input, LengthUnit::Meters, LengthUnit::Feet);
+        assert_eq!(originals, input);
+        assert_eq!(feet.len(), 1000);
+        for (meters, feet) in input.iter().zip(&feet) {
+            let expected = length::convert(*meters, LengthUnit::Meters, LengthUnit::Feet);
+            assert!((feet - expected).abs() <= 1e-12 * expected.abs());
+        }
+
+        assert_eq!(
+            length::convert_split(&[], LengthUnit::Meters, LengthUnit::Feet),
+            (vec![], vec![])
+        );
+    }
+
+    #[test]
+    fn test_temperature_within_deadband() {
+        use temperature::TemperatureUnit;
+
+        let setpoint = Quantity::new(21.0, TemperatureUnit::Celsius);
+        let deadband = Quantity::new(3.6, TemperatureUnit::Fahrenheit);
+
+        // 3.6 °F is 2 K wide, i.e. ±1 K around the setpoint
+        let inside = Quantity::new(294.9, TemperatureUnit::Kelvin);
+        assert_eq!(
+            temperature::within_deadband(inside, setpoint, deadband),
+            Ok(true)
+        );
+        let warm = Quantity::new(71.5, TemperatureUnit::Fahrenheit);
+        assert_eq!(
+            temperature::within_deadband(warm, setpoint, deadband),
+            Ok(true)
+        );
+        let outside = Quantity::new(22.2, TemperatureUnit::Celsius);
+        assert_eq!(
+            temperature::within_deadband(outside, setpoint, deadband),
+            Ok(false)
+        );
+        let below = Quantity::new(67.5, TemperatureUnit::Fahrenheit);
+        assert_eq!(
+            temperature::within_deadband(below, setpoint, deadband),
+            Ok(false)
+        );
+
+        // A 3 °De deadband is also 2 K wide, although Delisle runs backwards
+        let delisle_band = Quantity::new(3.0, TemperatureUnit::Delisle);
+        assert_eq!(
+            temperature::within_deadband(setpoint, setpoint, delisle_band),
+            Ok(true)
+        );
+        assert_eq!(
+            temperature::within_deadband(inside, setpoint, delisle_band),
+            Ok(true)
+        );
+        assert_eq!(
+            temperature::within_deadband(outside, setpoint, delisle_band),
+            Ok(false)
+        );
+
+        assert!(matches!(
+            temperature::within_deadband(
+                inside,
+                setpoint,
+                Quantity::new(-1.0, TemperatureUnit::Fahrenheit)
+            ),
+            Err(Error::InvalidInput(_))
+        ));
+        let meters = Quantity::new(1.0, length::LengthUnit::Meters);
+        assert!(matches!(
+            temperature::within_deadband(meters, meters, meters),
+            Err(Error::CategoryMismatch { .. })
+        ));
+        assert!(temperature::within_deadband(inside, setpoint, meters).is_err());
+    }
+
+    #[test]
+    fn test_util_safety_margin() {
+        use length::LengthUnit;
+
+        let margin = util::safety_margin(
+            Quantity::new(80.0, LengthUnit::Centimeters),
+            Quantity::new(1.0, LengthUnit::Meters),
+        )
+        .unwrap();
+        assert!((margin - 0.2).abs() < 1e-12);
+
+        let exceeded = util::safety_margin(
+            Quantity::new(1.5, mass::MassUnit::Tonnes),
+            Quantity::new(1000.0, mass::MassUnit::Kilograms),
+        )
+        .unwrap();
+        assert!((exceeded + 0.5).abs() < 1e-12);
+
+        assert!(matches!(
+            util::safety_margin(
+                Quantity::new(1.0, LengthUnit::Meters),
+                Quantity::new(1.0, mass::MassUnit::Kilograms)
+            ),
+            Err(Error::CategoryMismatch { .. })
+        ));
+        assert!(matches!(
+            util::safety_margin(
+                Quantity::new(1.0, LengthUnit::Meters),
+                Quantity::new(0.0, LengthUnit::Feet)
+            ),
+            Err(Error::DivisionByZero)
+        ));
+    }
+
+    #[test]
+    fn test_deprecated_units() {
+        use length::LengthUnit;
+
+        assert!(deprecated(LengthUnit::UsSurveyFeet));
+        assert!(
+            Unit::all()
+                .into_iter()
+                .filter(|&unit| deprecated(unit))
+                .count()
+                == 1
+        );
+        assert_eq!(
+            "ft_us".parse::<LengthUnit>().unwrap(),
+            LengthUnit::UsSurveyFeet
+        );
+
+        let (meters, warnings) =
+            convert_lenient(3937.0, LengthUnit::UsSurveyFeet, LengthUnit::Meters).unwrap();
+        assert!((meters - 1200.0).abs() < 1e-9);
+        assert_eq!(
+            warnings,
+            vec![Warning::DeprecatedUnit(LengthUnit::UsSurveyFeet.into())]
+        );
+        assert_eq!(warnings[0].to_string(), "ft_us is deprecated");
+
+        let (_, warnings) = convert_lenient(1.0, LengthUnit::Feet, LengthUnit::Meters).unwrap();
+        assert!(warnings.is_empty());
+
+        assert!(matches!(
+            convert_strict(1.0, LengthUnit::Meters, LengthUnit::UsSurveyFeet),
+            Err(Error::DeprecatedUnit(Unit::Length(
+                LengthUnit::UsSurveyFeet
+            )))
+        ));
+        assert_eq!(
+            convert_strict(1.0, LengthUnit::Kilometers, LengthUnit::Meters),
+            Ok(1000.0)
+        );
+    }
+
+    #[test]
+    fn test_export_factors_csv() {
+        let csv = export_factors_csv();
+        let mut lines = csv.lines();
+        assert_eq!(lines.next(), Some("category,from,to,factor"));
+
+        let rows: Vec<Vec<&str>> = lines.map(|line| line.split(',').collect()).collect();
+        assert!(rows.iter().all(|row| row.len() == 4));
+        assert!(rows.iter().all(|row| row[0] != "Temperature"));
+
+        let meters_to_feet = rows
+            .iter()
+            .find(|row| row[0] == "Length" && row[1] == "m" && row[2] == "ft")
+            .unwrap();
+        let factor: f64 = meters_to_feet[3].parse().unwrap();
+        assert!((factor - 1.0 / 0.3048).abs() < 1e-12);
+
+        let pairs = rows.iter().filter(|row| row[0] == "Pressure").count();
+        assert_eq!(pairs, 6);
+    }
+
+    #[test]
+    fn test_convert_with_sentinel() {
+        use length::LengthUnit;
+
+        assert_eq!(
+            convert_with_sentinel(-9999.0, LengthUnit::Meters, LengthUnit::Feet, -9999.0),
+            Ok(-9999.0)
+        );
+        let feet =
+            convert_with_sentinel(1.0, LengthUnit::Meters, LengthUnit::Feet, -9999.0).unwrap();
+        assert!((feet - 1.0 / 0.3048).abs() < 1e-12);
+
+        // Only the sentinel itself is exempt
+        let other =
+            convert_with_sentinel(-9998.0, LengthUnit::Meters, LengthUnit::Kilometers, -9999.0);
+        assert!((other.unwrap() + 9.998).abs() < 1e-12);
+        assert!(convert_with_sentinel(
+            -9999.0,
+            LengthUnit::Meters,
+            mass::MassUnit::Kilograms,
+            -9999.0
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_density_mixture_density() {
+        let blend = density::mixture_density(&[(1000.0, 0.5), (789.0, 0.5)]).unwrap();
+        assert!((blend - 894.5).abs() < 1e-9);
+
+        let three = density::mixture_density(&[(1000.0, 0.2), (850.0, 0.3), (700.0, 0.5)]).unwrap();
+        assert!((three - 805.0).abs() < 1e-9);
+        assert_eq!(density::mixture_density(&[(1.2, 1.0)]), Ok(1.2));
+
+        assert!(matches!(
+            density::mixture_density(&[(1000.0, 0.5), (800.0, 0.4)]),
+            Err(Error::InvalidInput(_))
+        ));
+        assert!(matches!(
+            density::mixture_density(&[(1000.0, 1.5), (800.0, -0.5)]),
+            Err(Error::InvalidInput(_))
+        ));
+        assert!(density::mixture_density(&[]).is_err());
+    }
+
+    #[test]
+    fn test_to_all_imperial() {
+        let lengths = to_all_imperial(1.0, Category::Length);
+        let symbols: Vec<&str> = lengths.iter().map(|&(symbol, _)| symbol).collect();
+        assert_eq!(symbols, ["in", "ft", "yd", "mi"]);
+        let yards = lengths
+            .iter()
+            .find(|&&(symbol, _)| symbol == "yd")
+            .unwrap()
+            .1;
+        assert!((yards - 1.0 / 0.9144).abs() < 1e-12);
+        let inches = lengths
+            .iter()
+            .find(|&&(symbol, _)| symbol == "in")
+            .unwrap()
+            .1;
+        assert!((inches - 39.37007874015748).abs() < 1e-9);
+
+        let temperatures = to_all_imperial(273.15, Category::Temperature);
+        assert_eq!(temperatures.len(), 1);
+        assert!((temperatures[0].1 - 32.0).abs() < 1e-9);
+
+        let masses = to_all_imperial(907.18474, Category::Mass);
+        assert!(masses.contains(&("ton", 1.0)));
+        assert_eq!(masses.len(), 2);
+    }
+
+    #[test]
+    fn test_quantity_partial_ord() {
+        use length::LengthUnit;
+        use std::cmp::Ordering;
+
+        let meter = Quantity::new(1.0, LengthUnit::Meters);
+        let three_feet = Quantity::new(3.0, LengthUnit::Feet);
+        assert!(meter > three_feet);
+        assert!(three_feet < meter);
+        let centimeters = Quantity::new(100.0, LengthUnit::Centimeters);
+        assert_eq!(meter.partial_cmp(&centimeters), Some(Ordering::Equal));
+        assert!(meter == centimeters && meter <= centimeters && meter >= centimeters);
+        assert_ne!(meter, three_feet);
+
+        let kilogram = Quantity::new(1.0, mass::MassUnit::Kilograms);
+        assert_eq!(meter.partial_cmp(&kilogram), None);
+        assert!(!meter.lt(&kilogram) && !meter.gt(&kilogram));
+        assert_ne!(meter, kilogram);
+
+        let mut lengths = [meter, three_feet, Quantity::new(0.5, LengthUnit::Yards)];
+        lengths.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        assert_eq!(lengths[0].unit, Unit::Length(LengthUnit::Yards));
+        assert_eq!(lengths[2], meter);
+    }
+
+    #[test]
+    fn test_energy_calories() {
+        assert_eq!(energy::kcal_to_joules(1.0), 4184.0);
+        assert_eq!(
+            energy::small_cal_to_joules(1000.0),
+            energy::kcal_to_joules(1.0)
+        );
+        assert_eq!(energy::small_cal_to_joules(1.0), 4.184);
+        assert_eq!(energy::joules_to_kcal(4184.0), 1.0);
+        assert!((energy::joules_to_kcal(energy::small_cal_to_joules(1.0)) - 0.001).abs() < 1e-15);
+    }
+
+    #[test]
+    fn test_pipeline() {
+        use length::LengthUnit;
+
+        let pipeline = Pipeline::new()
+            .then(LengthUnit::Meters, LengthUnit::Feet)
+            .then(LengthUnit::Feet, LengthUnit::Inches);
+        let inches = pipeline.apply_slice(&[0.0, 0.0254, 1.0, -2.0]).unwrap();
+        let expected = [0.0, 1.0, 1.0 / 0.0254, -2.0 / 0.0254];
+        for (inches, expected) in inches.iter().zip(expected) {
+            assert!((inches - expected).abs() < 1e-9);
+        }
+        assert_eq!(Pipeline::new().apply(42.0), Ok(42.0));
+
+        let broken = Pipeline::new()
+            .then(LengthUnit::Meters, LengthUnit::Feet)
+            .then(LengthUnit::Yards, LengthUnit::Inches);
+        assert!(matches!(broken.apply(1.0), Err(Error::InvalidInput(_))));
+        assert!(matches!(
+            Pipeline::new()
+                .then(LengthUnit::Meters, mass::MassUnit::Kilograms)
+                .apply_slice(&[1.0]),
+            Err(Error::CategoryMismatch { .. })
+        ));
+    }
+
+    #[test]
+    fn test_preferred_units() {
+        use length::LengthUnit;
+        use pressure::PressureUnit;
+
+        let plumbing = preferred_units(Context::Plumbing);
+        assert_eq!(plumbing.pressure, PressureUnit::Psi);
+        assert_eq!(plumbing.length, LengthUnit::Inches);
+
+        let aviation = preferred_units(Context::Aviation);
+        assert_eq!(aviation.length, LengthUnit::Feet);
+        assert_eq!(
+            aviation.unit_for(Category::Length),
+            Unit::Length(LengthUnit::Feet)
+        );
+
+        let general = preferred_units(Context::General);
+        for category in Category::ALL {
+            assert_eq!(general.unit_for(category).category(), category);
+            assert_eq!(
+                system(general.unit_for(category)),
+                MeasurementSystem::Metric
+            );
+        }
+    }
+
+    #[test]
+    fn test_metrics_count_failures() {
+        use length::LengthUnit;
+
+        // Other tests fail conversions concurrently, so only lower bounds hold
+        let before = metrics().snapshot();
+        for _ in 0..3 {
+            assert!(convert(1.0, LengthUnit::Meters, mass::MassUnit::Kilograms).is_err());
+        }
+        assert!(convert_strict(1.0, LengthUnit::UsSurveyFeet, LengthUnit::Feet).is_err());
+        assert!(convert_any(1.0, "cubit", "m").is_err());
+        for _ in 0..2 {
+            assert!("furlong".parse::<Unit>().is_err());
+        }
+        let after = metrics().snapshot();
+
+        assert!(after["CategoryMismatch"] >= before["CategoryMismatch"] + 3);
+        assert!(after["DeprecatedUnit"] > before["DeprecatedUnit"]);
+        assert!(after["UnknownUnit"] >= before["UnknownUnit"] + 3);
+        assert_eq!(after.len(), ERROR_KINDS.len());
+
+        let local = Metrics::new();
+        local.record(&Error::Parse("x".to_string()));
+        local.record(&Error::Parse("y".to_string()));
+        assert_eq!(local.snapshot()["Parse"], 2);
+        local.reset();
+        assert!(local.snapshot().values().all(|&count| count == 0));
+    }
+
+    #[test]
+    fn test_parse_unicode_unit_symbols() {
+        use length::LengthUnit;
+        use temperature::TemperatureUnit;
+
+        let celsius = Unit::Temperature(TemperatureUnit::Celsius);
+        for symbol in ["°C", "degC", "C", "\u{2103}", "\u{ba}C", "\u{2da}C"] {
+            assert_eq!(symbol.parse::<Unit>().unwrap(), celsius, "{:?}", symbol);
+        }
+        assert_eq!(
+            "\u{2109}".parse::<TemperatureUnit>().unwrap(),
+            TemperatureUnit::Fahrenheit
+        );
+        assert_eq!(
+            "\u{212a}".parse::<TemperatureUnit>().unwrap(),
+            TemperatureUnit::Kelvin
+        );
+
+        let micrometers = Unit::Length(LengthUnit::Micrometers);
+        for symbol in ["µm", "\u{3bc}m", "um"] {
+            assert_eq!(symbol.parse::<Unit>().unwrap(), micrometers, "{:?}", symbol);
+        }
+
+        // Stray combining marks and zero-width characters are ignored
+        assert_eq!("µ\u{301}m".parse::<Unit>().unwrap(), micrometers);
+        assert_eq!("\u{feff}°C".parse::<Unit>().unwrap(), celsius);
+        assert!(matches!(
+            "\u{301}".parse::<Unit>(),
+            Err(Error::UnknownUnit(symbol)) if symbol == "\u{301}"
+        ));
+    }
+
+    #[test]
+    fn test_demo_diff_specs() {
+        use temperature::TemperatureUnit;
+
+        let before = demo::FacilitySpec {
+            capacity_tonnes_per_day: 500.0,
+            operating_temp_c: 37.0,
+            operating_pressure_bar: 1.5,
+        };
+        let after = demo::FacilitySpec {
+            operating_temp_c: 55.0,
+            ..before
+        };
+        assert!(demo::diff_specs(&before, &before).is_empty());
+
+        let diffs = demo::diff_specs(&before, &after);
+        assert_eq!(diffs.len(), 1);
+        let diff = diffs[0];
+        assert_eq!(diff.field, "operating_temp_c");
+        assert_eq!(diff.old, Quantity::new(37.0, TemperatureUnit::Celsius));
+        assert_eq!(diff.new, Quantity::new(55.0, TemperatureUnit::Celsius));
+        assert_eq!(diff.delta, 18.0);
+        assert_eq!(
+            diff.new_imperial.unit,
+            Unit::Temperature(TemperatureUnit::Fahrenheit)
+        );
+        assert!((diff.old_imperial.value - 98.6).abs() < 1e-9);
+        assert!((diff.new_imperial.value - 131.0).abs() < 1e-9);
+        let percent = diff.percent_change.unwrap();
+        assert!((percent - 18.0 / 310.15 * 100.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_qty_macro() {
+        use length::LengthUnit;
+
+        const HEIGHT: Quantity = qty!(2.5 m);
+        assert_eq!(HEIGHT, Quantity::new(2.5, LengthUnit::Meters));
+        assert_eq!(
+            qty!(25 C),
+            Quantity::new(25.0, temperature::TemperatureUnit::Celsius)
+        );
+        assert_eq!(qty!(12 in), Quantity::new(12.0, LengthUnit::Inches));
+        assert_eq!(qty!(3 gal_us).unit.symbol(), "gal_us");
+
+        for (quantity, symbol) in [
+            (qty!(1 um), "µm"),
+            (qty!(1 ft3), "ft³"),
+            (qty!(1 De), "°De"),
+            (qty!(1 t), "t"),
+        ] {
+            assert_eq!(quantity.unit.symbol(), symbol);
+        }
+    }
+
+    #[test]
+    fn test_temperature_celsius_to_fixed_i16() {
+        assert_eq!(temperature::celsius_to_fixed_i16(21.37, 10), Ok(214));
+        assert_eq!(temperature::celsius_to_fixed_i16(-12.25, 100), Ok(-1225));
+        assert_eq!(temperature::celsius_to_fixed_i16(0.05, 10), Ok(1));
+        assert_eq!(temperature::celsius_to_fixed_i16(4000.0, 10), Ok(i16::MAX));
+        assert_eq!(temperature::celsius_to_fixed_i16(-4000.0, 10), Ok(i16::MIN));
+        assert!(matches!(
+            temperature::celsius_to_fixed_i16(f64::NAN, 10),
+            Err(Error::InvalidInput(_))
+        ));
+        assert!(temperature::celsius_to_fixed_i16(f64::INFINITY, 1).is_err());
+    }
+
+    #[test]
+    fn test_affine_calibration_set() {
+        let mut sensors = CalibrationSet::new();
+        let flow = AffineConversion::new(2.5, -10.0);
+        assert_eq!(sensors.register("FT-201", flow), None);
+        sensors.register("PT-301", AffineConversion::new(0.625, -2.5));
+        assert_eq!(sensors.get("FT-201"), Some(flow));
+
+        assert_eq!(sensors.apply("FT-201", 8.0), Ok(10.0));
+        assert_eq!(sensors.invert("FT-201", 10.0), Ok(8.0));
+        for raw in [4.0, 7.3, 12.0, 20.0] {
+            let value = sensors.apply("PT-301", raw).unwrap();
+            assert!((sensors.invert("PT-301", value).unwrap() - raw).abs() < 1e-12);
+        }
+        assert!(matches!(
+            sensors.apply("XX-999", 1.0),
+            Err(Error::InvalidInput(_))
+        ));
+        assert_eq!(
+            sensors.invert("XX-999", 1.0),
+            Err(Error::InvalidInput(
+                "no calibration named \"XX-999\"".to_string()
+            ))
+        );
+
+        let kind = ConversionKind::from(flow);
+        assert_eq!(kind.apply(8.0), Ok(flow.apply(8.0)));
+    }
+
+    #[test]
+    #[should_panic(expected = "scale must be non-zero")]
+    fn test_affine_conversion_zero_scale() {
+        AffineConversion::new(0.0, 1.0);
+    }
+
+    #[test]
+    fn test_volume_sum_checked_mixed_gallons() {
+        use volume::VolumeUnit;
+
+        let mixed = [
+            Quantity::new(1.0, VolumeUnit::UsGallons),
+            Quantity::new(1.0, VolumeUnit::ImperialGallons),
+        ];
+        let liters = volume::sum_checked(&mixed, VolumeUnit::Liters).unwrap();
+        assert_eq!(liters.unit, Unit::Volume(VolumeUnit::Liters));
+        assert!((liters.value - (3.785411784 + 4.54609)).abs() < 1e-9);
+        assert!((liters.value - (3.785 + 4.546)).abs() < 1e-3);
+
+        let us = volume::sum_checked(&mixed, VolumeUnit::UsGallons).unwrap();
+        assert!((us.value - (1.0 + 4.54609 / 3.785411784)).abs() < 1e-9);
+        assert_eq!(
+            volume::sum_checked(&[], VolumeUnit::Liters).unwrap().value,
+            0.0
+        );
+        assert!(matches!(
+            volume::sum_checked(
+                &[Quantity::new(1.0, mass::MassUnit::Kilograms)],
+                VolumeUnit::Liters
+            ),
+            Err(Error::CategoryMismatch { .. })
+        ));
+    }
+
+    #[test]
+    fn test_category_converter_trait_objects() {
+        use length::LengthUnit;
+        use mass::MassUnit;
+
+        let converters: Vec<Box<dyn CategoryConverter>> = vec![
+            Box::new(length::LengthCategory),
+            Box::new(mass::MassCategory),
+        ];
+        let feet = converters[0]
+            .convert(1.0, LengthUnit::Meters.into(), LengthUnit::Feet.into())
+            .unwrap();
+        assert!((feet - 3.280839895).abs() < 1e-9);
+        let kg = converters[1]
+            .convert(1000.0, MassUnit::Grams.into(), MassUnit::Kilograms.into())
+            .unwrap();
+        assert!((kg - 1.0).abs() < 1e-12);
+        assert!(matches!(
+            converters[0].convert(1.0, MassUnit::Kilograms.into(), MassUnit::Pounds.into()),
+            Err(Error::CategoryMismatch { .. })
+        ));
+        assert!(converters[1]
+            .units()
+            .contains(&Unit::Mass(MassUnit::Tonnes)));
+
+        for category in Category::ALL {
+            let converter = category.converter();
+            assert_eq!(converter.category(), category);
+            assert!(converter.units().contains(&category.base_unit()));
+        }
+    }
+
+    #[test]
+    fn test_error_kinds_cover_every_variant() {
+        use length::LengthUnit;
+
+        let unit = Unit::Length(LengthUnit::Meters);
+        let zero = Quantity::new(0.0, unit);
+        let samples = [
+            Error::UnknownUnit(String::new()),
+            Error::CategoryMismatch {
+                from: Category::Length,
+                to: Category::Mass,
+            },
+            Error::DivisionByZero,
+            Error::OutOfRange {
+                value: zero,
+                min: zero,
+                max: zero,
+            },
+            Error::InvalidRange {
+                min: zero,
+                max: zero,
+            },
+            Error::Parse(String::new()),
+            Error::InvalidInput(String::new()),
+            Error::UnknownPlaceholder(String::new()),
+            Error::ErrorBudgetExceeded {
+                estimated: 0.0,
+                budget: 0.0,
+            },
+            Error::InvalidOperationForScale(unit),
+            Error::NoConversionPath {
+                from: String::new(),
+                to: String::new(),
+            },
+            Error::RatioRequiresAbsoluteScale(unit),
+            Error::DeprecatedUnit(unit),
+        ];
+
+        assert_eq!(samples.len(), ERROR_KINDS.len());
+        for (index, sample) in samples.iter().enumerate() {
+            assert_eq!(sample.index(), index);
+            assert!(format!("{:?}", sample).starts_with(ERROR_KINDS[index]));
+        }
     }
 }
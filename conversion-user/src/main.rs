@@ -2,8 +2,20 @@ use conversion_lib::length;
 use conversion_lib::temperature;
 use conversion_lib::pressure;
 use conversion_lib::mass;
+use conversion_lib::demo::FacilitySpec;
+
+mod repl;
 
 fn main() {
+    if std::env::args().nth(1).as_deref() == Some("repl") {
+        let stdin = std::io::stdin();
+        if let Err(error) = repl::run(stdin.lock(), std::io::stdout()) {
+            eprintln!("error: {}", error);
+            std::process::exit(1);
+        }
+        return;
+    }
+
     println!("=== Engineering Unit Conversion Demo ===\n");
 
     // Length conversions
@@ -45,15 +57,10 @@ fn main() {
     println!("  {} tonnes/day = {:.2} tons/day", tonnes, tons);
     
     println!("\n=== Practical Engineering Example ===");
-    println!("AD Facility Specifications:");
-    let daily_waste_tonnes = 500.0;
-    let daily_waste_tons = mass::tonnes_to_tons(daily_waste_tonnes);
-    let operating_temp_c = 55.0; // Thermophilic digestion
-    let operating_temp_f = temperature::celsius_to_fahrenheit(operating_temp_c);
-    let operating_pressure_bar = 1.5;
-    let operating_pressure_psi = pressure::bar_to_psi(operating_pressure_bar);
-    
-    println!("  Capacity: {} tonnes/day ({:.2} tons/day)", daily_waste_tonnes, daily_waste_tons);
-    println!("  Operating Temperature: {}°C ({:.2}°F)", operating_temp_c, operating_temp_f);
-    println!("  Operating Pressure: {} bar ({:.2} PSI)", operating_pressure_bar, operating_pressure_psi);
+    let facility = FacilitySpec {
+        capacity_tonnes_per_day: 500.0,
+        operating_temp_c: 55.0, // Thermophilic digestion
+        operating_pressure_bar: 1.5,
+    };
+    print!("{}", facility.report());
 }
@@ -0,0 +1,56 @@
+//! Interactive conversion loop for `conversion-user repl`
+
+use conversion_lib::{convert, default_precision, Unit};
+use std::io::{self, BufRead, Write};
+
+/// Reads commands from `input` until EOF, writing results to `output`
+///
+/// Each line is either a conversion such as `100 m to ft`, `list units`, or
+/// `quit`. Errors are reported on `output` and the loop carries on.
+pub fn run(input: impl BufRead, mut output: impl Write) -> io::Result<()> {
+    for line in input.lines() {
+        let line = line?;
+        let command = line.trim();
+        match command {
+            "" => continue,
+            "quit" | "exit" => break,
+            "list units" => {
+                for unit in Unit::all() {
+                    writeln!(output, "{:<8} {:?}", unit.symbol(), unit.category())?;
+                }
+            }
+            _ => match evaluate(command) {
+                Ok(result) => writeln!(output, "{}", result)?,
+                Err(message) => writeln!(output, "error: {}", message)?,
+            },
+        }
+    }
+    Ok(())
+}
+
+/// Evaluates a single `<value> <unit> to <unit>` conversion
+fn evaluate(command: &str) -> Result<String, String> {
+    let usage = || format!("expected \"<value> <unit> to <unit>\", got {:?}", command);
+
+    let (quantity, target) = command.split_once(" to ").ok_or_else(usage)?;
+    let (value, from) = quantity
+        .trim()
+        .split_once(char::is_whitespace)
+        .ok_or_else(usage)?;
+
+    let value: f64 = value
+        .parse()
+        .map_err(|_| format!("invalid number {:?}", value))?;
+    let from: Unit = from.trim().parse().map_err(|e| format!("{}", e))?;
+    let to: Unit = target.trim().parse().map_err(|e| format!("{}", e))?;
+    let result = convert(value, from, to).map_err(|e| format!("{}", e))?;
+
+    Ok(format!(
+        "{} {} = {:.*} {}",
+        value,
+        from,
+        default_precision(),
+        result,
+        to
+    ))
+}
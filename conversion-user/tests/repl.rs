@@ -0,0 +1,50 @@
+use std::io::Write;
+use std::process::{Command, Stdio};
+
+fn run_repl(input: &str) -> String {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_conversion-user"))
+        .arg("repl")
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()
+        .expect("failed to start conversion-user");
+
+    child
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(input.as_bytes())
+        .unwrap();
+    let output = child.wait_with_output().unwrap();
+    assert!(output.status.success());
+    String::from_utf8(output.stdout).unwrap()
+}
+
+#[test]
+fn test_repl_converts_until_eof() {
+    let output = run_repl("100 m to ft\n\n2.5 bar to psi\n");
+    let lines: Vec<&str> = output.lines().collect();
+    assert_eq!(lines, ["100 m = 328.08 ft", "2.5 bar = 36.26 psi"]);
+}
+
+#[test]
+fn test_repl_reports_errors_and_continues() {
+    let output = run_repl("1 m to kg\nabc m to ft\n1 km to mi\n");
+    let lines: Vec<&str> = output.lines().collect();
+    assert_eq!(lines.len(), 3);
+    assert!(lines[0].starts_with("error: cannot combine Length with Mass"));
+    assert!(lines[1].starts_with("error: invalid number"));
+    assert_eq!(lines[2], "1 km = 0.62 mi");
+}
+
+#[test]
+fn test_repl_list_units_and_quit() {
+    let output = run_repl("list units\nquit\n1 m to ft\n");
+    assert!(output
+        .lines()
+        .any(|line| line.starts_with("ft ") && line.ends_with("Length")));
+    assert!(output
+        .lines()
+        .any(|line| line.starts_with("°C") && line.ends_with("Temperature")));
+    assert!(!output.contains("1 m ="));
+}